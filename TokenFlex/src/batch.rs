@@ -80,6 +80,7 @@ pub fn batch_transfer(
 }
 
 // Tekrarlayan ödeme planla
+#[allow(dead_code)]
 pub fn schedule_recurring_payment(
     _e: &Env,
     _from: Address,
@@ -1,5 +1,8 @@
-use soroban_sdk::{Address, Env, contracterror, contracttype};
-use crate::storage_types::{DataKey};
+use soroban_sdk::{Address, Bytes, BytesN, Env, Error, Symbol, Val, Vec, contracterror, contracttype};
+use crate::balance::{receive_balance, spend_balance};
+use crate::contract::is_account_frozen;
+use crate::storage_types::{DataKey, BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD};
+use soroban_token_sdk::TokenUtils;
 
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -10,24 +13,293 @@ pub enum ConditionalError {
     TransferAlreadyExecuted = 3,
     InvalidTransferAmount = 4,
     TransferExpired = 5,
+    AccountFrozen = 6,
+    TransferNotFound = 7,
+    TransferCancelled = 8,
+    ConsentRequired = 9,
+    NotAnApprover = 10,
+    AlreadyApproved = 11,
+    TooManyPending = 12,
 }
 
+// Sayfalı sorgularda tek seferde döndürülecek en fazla kayıt
+pub const MAX_PAGE_SIZE: u32 = 50;
+
+// Bir alıcıya bekleyen en fazla koşullu transfer; alıcı istemediklerini reddedebilir
+pub const MAX_PENDING_PER_RECIPIENT: u32 = 100;
+
+// Koşul ağacının izin verilen en fazla derinliği
+pub const MAX_CONDITION_DEPTH: u32 = 4;
+
+#[derive(Clone)]
 #[contracttype]
 pub enum ConditionType {
     TimeBasedRelease(u32),     // Belirli bir ledger'dan sonra serbest bırakılır
     ApprovalRequired(Address), // Belirli bir adresin onayı gerekmektedir
+    MultiApproval(MultiApprovalCondition), // N onaylayıcıdan en az M tanesinin onayı gerekmektedir
+    All(Vec<ConditionType>),   // Tüm alt koşullar sağlanmalıdır
+    Any(Vec<ConditionType>),   // Alt koşullardan en az biri sağlanmalıdır
+    Not(Vec<ConditionType>),   // Tek alt koşul sağlanmamalıdır
+    HashLock(BytesN<32>),      // sha256 özeti eşleşen ön görüntü sunulmalıdır
+    ContractCheck(ContractCheckCondition), // Harici kontrat true döndürmelidir
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct MultiApprovalCondition {
+    pub approvers: Vec<Address>,
+    pub threshold: u32,
+}
+
+// Harici bir kontratın (ör. teslimat onayı, fiyat kahini) sorgulanacak fonksiyonu
+#[derive(Clone)]
+#[contracttype]
+pub struct ContractCheckCondition {
+    pub contract: Address,
+    pub fn_name: Symbol,
+    pub args: Vec<Val>,
 }
 
 #[contracttype]
 pub struct ConditionalTransfer {
+    pub id: u32,
     pub from: Address,
     pub to: Address,
     pub amount: i128,
     pub condition: ConditionType,
     pub executed: bool,
+    pub cancelled: bool,
     pub expiration_ledger: u32,  // Opsiyonel son kullanma tarihi
 }
 
+fn read_conditional_transfer(e: &Env, transfer_id: u32) -> Option<ConditionalTransfer> {
+    let key = DataKey::ConditionalTransfer(transfer_id);
+    let transfer = e.storage().persistent().get::<_, ConditionalTransfer>(&key);
+    if transfer.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+    }
+    transfer
+}
+
+fn write_conditional_transfer(e: &Env, transfer: &ConditionalTransfer) {
+    let key = DataKey::ConditionalTransfer(transfer.id);
+    e.storage().persistent().set(&key, transfer);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+// Sayaçtan yeni bir transfer kimliği ayır
+fn next_transfer_id(e: &Env) -> u32 {
+    let key = DataKey::ConditionalTransferCount;
+    let transfer_id = e.storage().instance().get::<_, u32>(&key).unwrap_or(0) + 1;
+    e.storage().instance().set(&key, &transfer_id);
+    transfer_id
+}
+
+fn read_index(e: &Env, key: &DataKey) -> Vec<u32> {
+    if let Some(ids) = e.storage().persistent().get::<_, Vec<u32>>(key) {
+        e.storage()
+            .persistent()
+            .extend_ttl(key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+        ids
+    } else {
+        Vec::new(e)
+    }
+}
+
+fn write_index(e: &Env, key: &DataKey, ids: &Vec<u32>) {
+    if ids.is_empty() {
+        e.storage().persistent().remove(key);
+    } else {
+        e.storage().persistent().set(key, ids);
+        e.storage()
+            .persistent()
+            .extend_ttl(key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+    }
+}
+
+// Bekleyen transferi gönderici ve alıcı indekslerine ekle
+fn add_to_indexes(e: &Env, transfer: &ConditionalTransfer) {
+    for key in [
+        DataKey::ConditionalsBySender(transfer.from.clone()),
+        DataKey::ConditionalsByRecipient(transfer.to.clone()),
+    ] {
+        let mut ids = read_index(e, &key);
+        ids.push_back(transfer.id);
+        write_index(e, &key, &ids);
+    }
+}
+
+// Sonuçlanan transferi indekslerden çıkar
+fn remove_from_indexes(e: &Env, transfer: &ConditionalTransfer) {
+    for key in [
+        DataKey::ConditionalsBySender(transfer.from.clone()),
+        DataKey::ConditionalsByRecipient(transfer.to.clone()),
+    ] {
+        let mut ids = read_index(e, &key);
+        if let Some(index) = ids.first_index_of(transfer.id) {
+            ids.remove(index);
+        }
+        write_index(e, &key, &ids);
+    }
+}
+
+fn is_expired(e: &Env, transfer: &ConditionalTransfer) -> bool {
+    transfer.expiration_ledger > 0 && e.ledger().sequence() > transfer.expiration_ledger
+}
+
+// Adres, koşul ağacının herhangi bir yerinde onaylayıcı olarak belirtilmiş mi
+pub(crate) fn is_designated_approver(condition: &ConditionType, addr: &Address) -> bool {
+    match condition {
+        ConditionType::ApprovalRequired(approver) => approver == addr,
+        ConditionType::MultiApproval(multi) => multi.approvers.contains(addr),
+        ConditionType::All(children) | ConditionType::Any(children) | ConditionType::Not(children) => {
+            children.iter().any(|child| is_designated_approver(&child, addr))
+        },
+        ConditionType::TimeBasedRelease(_)
+        | ConditionType::HashLock(_)
+        | ConditionType::ContractCheck(_) => false,
+    }
+}
+
+// Koşul ağacında hash kilidi var mı
+pub(crate) fn contains_hash_lock(condition: &ConditionType) -> bool {
+    match condition {
+        ConditionType::HashLock(_) => true,
+        ConditionType::All(children) | ConditionType::Any(children) | ConditionType::Not(children) => {
+            children.iter().any(|child| contains_hash_lock(&child))
+        },
+        _ => false,
+    }
+}
+
+// Koşul parametrelerini ve ağaç derinliğini doğrula
+pub(crate) fn validate_condition(condition: &ConditionType, depth: u32) -> Result<(), ConditionalError> {
+    if depth > MAX_CONDITION_DEPTH {
+        return Err(ConditionalError::InvalidCondition);
+    }
+    match condition {
+        ConditionType::MultiApproval(multi) => {
+            if multi.threshold == 0 || multi.threshold > multi.approvers.len() {
+                return Err(ConditionalError::InvalidCondition);
+            }
+            // Aynı onaylayıcı birden fazla kez yazılamaz
+            for i in 0..multi.approvers.len() {
+                if multi.approvers.first_index_of(multi.approvers.get(i).unwrap()) != Some(i) {
+                    return Err(ConditionalError::InvalidCondition);
+                }
+            }
+        },
+        ConditionType::All(children) | ConditionType::Any(children) => {
+            if children.is_empty() {
+                return Err(ConditionalError::InvalidCondition);
+            }
+            for child in children.iter() {
+                validate_condition(&child, depth + 1)?;
+            }
+        },
+        ConditionType::Not(children) => {
+            if children.len() != 1 {
+                return Err(ConditionalError::InvalidCondition);
+            }
+            validate_condition(&children.get(0).unwrap(), depth + 1)?;
+        },
+        ConditionType::TimeBasedRelease(_)
+        | ConditionType::ApprovalRequired(_)
+        | ConditionType::HashLock(_)
+        | ConditionType::ContractCheck(_) => {},
+    }
+    Ok(())
+}
+
+// Koşul değerlendirmesi için yürütme anındaki bilgiler
+pub(crate) struct ConditionContext {
+    pub current_ledger: u32,
+    pub approver: Option<Address>,
+    pub approvals: Vec<Address>,
+    pub preimage: Option<Bytes>,
+}
+
+// Koşul ağacını değerlendir; sağlanmayan dalın kökten itibaren indeks yolunu döndür
+pub(crate) fn evaluate_condition(
+    e: &Env,
+    condition: &ConditionType,
+    ctx: &ConditionContext
+) -> Result<(), Vec<u32>> {
+    let met = match condition {
+        ConditionType::TimeBasedRelease(release_ledger) => ctx.current_ledger >= *release_ledger,
+        ConditionType::ApprovalRequired(approver_address) => {
+            // Onay ya bu çağrıda verilir ya da önceden kaydedilmiş olmalıdır
+            ctx.approver.as_ref() == Some(approver_address) || ctx.approvals.contains(approver_address)
+        },
+        ConditionType::MultiApproval(multi) => {
+            // Ayrı işlemlerde kaydedilen onayları say
+            let mut approved: u32 = 0;
+            for addr in multi.approvers.iter() {
+                if ctx.approvals.contains(&addr) {
+                    approved += 1;
+                }
+            }
+            approved >= multi.threshold
+        },
+        ConditionType::All(children) => {
+            for (i, child) in children.iter().enumerate() {
+                if let Err(mut path) = evaluate_condition(e, &child, ctx) {
+                    path.push_front(i as u32);
+                    return Err(path);
+                }
+            }
+            true
+        },
+        ConditionType::Any(children) => {
+            children.iter().any(|child| evaluate_condition(e, &child, ctx).is_ok())
+        },
+        ConditionType::Not(children) => {
+            evaluate_condition(e, &children.get(0).unwrap(), ctx).is_err()
+        },
+        ConditionType::HashLock(hash) => match ctx.preimage {
+            Some(ref preimage) => BytesN::from(e.crypto().sha256(preimage)) == *hash,
+            None => false,
+        },
+        ConditionType::ContractCheck(check) => {
+            // Çağrı hata verirse ya da true dışında bir değer dönerse koşul sağlanmamış sayılır
+            matches!(
+                e.try_invoke_contract::<bool, Error>(&check.contract, &check.fn_name, check.args.clone()),
+                Ok(Ok(true))
+            )
+        },
+    };
+
+    if met {
+        Ok(())
+    } else {
+        Err(Vec::new(e))
+    }
+}
+
+fn read_approvals(e: &Env, transfer_id: u32) -> Vec<Address> {
+    let key = DataKey::ConditionalApprovals(transfer_id);
+    if let Some(approvals) = e.storage().persistent().get::<_, Vec<Address>>(&key) {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+        approvals
+    } else {
+        Vec::new(e)
+    }
+}
+
+fn write_approvals(e: &Env, transfer_id: u32, approvals: &Vec<Address>) {
+    let key = DataKey::ConditionalApprovals(transfer_id);
+    e.storage().persistent().set(&key, approvals);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
 // Koşullu bir transfer oluştur
 pub fn create_conditional_transfer(
     e: &Env,
@@ -36,30 +308,53 @@ pub fn create_conditional_transfer(
     amount: i128,
     condition: ConditionType,
     expiration_ledger: u32
-) -> Result<(), ConditionalError> {
+) -> Result<u32, ConditionalError> {
     // Parametreleri doğrula
     if amount <= 0 {
         return Err(ConditionalError::InvalidTransferAmount);
     }
+    validate_condition(&condition, 1)?;
+
+    // Hash kilitli transferler iade için bir son kullanma tarihine ihtiyaç duyar
+    if contains_hash_lock(&condition) && expiration_ledger == 0 {
+        return Err(ConditionalError::InvalidCondition);
+    }
     
     // Yetkilendirme iste
     from.require_auth();
+
+    // Göndericinin hesabı dondurulmuş mu kontrol et
+    if is_account_frozen(e, &from) {
+        return Err(ConditionalError::AccountFrozen);
+    }
+
+    // Alıcının indeksi sınırsız büyüyemez
+    if read_index(e, &DataKey::ConditionalsByRecipient(to.clone())).len() >= MAX_PENDING_PER_RECIPIENT {
+        return Err(ConditionalError::TooManyPending);
+    }
+
+    // Tutarı göndericiden kontrat emanetine aktar
+    spend_balance(e, from.clone(), amount);
+    receive_balance(e, e.current_contract_address(), amount);
+    TokenUtils::new(e).events().transfer(from.clone(), e.current_contract_address(), amount);
     
-    let transfer_id = e.ledger().sequence(); // Her transfer için benzersiz bir kimlik
+    let transfer_id = next_transfer_id(e); // Her transfer için benzersiz bir kimlik
     
     // Koşullu transferi oluştur
     let transfer = ConditionalTransfer {
+        id: transfer_id,
         from: from.clone(),
         to: to.clone(),
         amount,
         condition,
         executed: false,
+        cancelled: false,
         expiration_ledger,
     };
     
     // Transferi kaydet
-    let key = DataKey::ConditionalTransfer(transfer_id);
-    e.storage().persistent().set(&key, &transfer);
+    write_conditional_transfer(e, &transfer);
+    add_to_indexes(e, &transfer);
     
     // Olay yayınla
     e.events().publish(
@@ -67,68 +362,252 @@ pub fn create_conditional_transfer(
         (amount, transfer_id)
     );
     
-    Ok(())
+    Ok(transfer_id)
 }
 
 // Koşullu bir transferi yürüt
 pub fn execute_conditional_transfer(
     e: &Env,
     transfer_id: u32,
-    approver: Option<Address>
+    approver: Option<Address>,
+    preimage: Option<Bytes>
 ) -> Result<(), ConditionalError> {
-    let key = DataKey::ConditionalTransfer(transfer_id);
-    
     // Transferi kontrol et
-    if !e.storage().persistent().has(&key) {
-        return Err(ConditionalError::InvalidCondition);
-    }
+    let mut transfer = read_conditional_transfer(e, transfer_id)
+        .ok_or(ConditionalError::TransferNotFound)?;
     
-    let mut transfer: ConditionalTransfer = e.storage().persistent().get(&key).unwrap();
-    
-    // Zaten çalıştırılmış mı kontrol et
+    // Zaten çalıştırılmış ya da iptal edilmiş mi kontrol et
     if transfer.executed {
         return Err(ConditionalError::TransferAlreadyExecuted);
     }
+    if transfer.cancelled {
+        return Err(ConditionalError::TransferCancelled);
+    }
     
     // Son kullanma tarihini kontrol et
     let current_ledger = e.ledger().sequence();
-    if transfer.expiration_ledger > 0 && current_ledger > transfer.expiration_ledger {
+    if is_expired(e, &transfer) {
         return Err(ConditionalError::TransferExpired);
     }
     
-    // Koşulu kontrol et
-    match transfer.condition {
-        ConditionType::TimeBasedRelease(release_ledger) => {
-            if current_ledger < release_ledger {
-                return Err(ConditionalError::ConditionNotMet);
-            }
-        },
-        ConditionType::ApprovalRequired(ref approver_address) => {
-            if let Some(addr) = approver {
-                if addr != *approver_address {
-                    return Err(ConditionalError::ConditionNotMet);
-                }
-                // Onaylayıcı yetkilendirmesi iste
-                addr.require_auth();
-            } else {
-                return Err(ConditionalError::ConditionNotMet);
-            }
-        }
+    // Koşul ağacının tamamını değerlendir
+    let ctx = ConditionContext {
+        current_ledger,
+        approver: approver.clone(),
+        approvals: read_approvals(e, transfer_id),
+        preimage: preimage.clone(),
+    };
+    if evaluate_condition(e, &transfer.condition, &ctx).is_err() {
+        return Err(ConditionalError::ConditionNotMet);
+    }
+
+    // Onaylayıcı yetkilendirmesi iste
+    if let Some(addr) = approver {
+        addr.require_auth();
+    }
+    
+    // Taraflardan biri dondurulmuşsa emanet serbest bırakılamaz
+    if is_account_frozen(e, &transfer.from) || is_account_frozen(e, &transfer.to) {
+        return Err(ConditionalError::AccountFrozen);
     }
     
-    // Transferi gerçekleştir
-    // Burada, gerçek transferi uygulamak için transfer kullanılabilir
-    // Bu, balance.rs'deki fonksiyonları çağırarak yapılır
+    // Emanetteki tutarı alıcıya öde
+    spend_balance(e, e.current_contract_address(), transfer.amount);
+    receive_balance(e, transfer.to.clone(), transfer.amount);
+    TokenUtils::new(e)
+        .events()
+        .transfer(e.current_contract_address(), transfer.to.clone(), transfer.amount);
     
     // Transferi yürütüldü olarak işaretle
     transfer.executed = true;
-    e.storage().persistent().set(&key, &transfer);
+    write_conditional_transfer(e, &transfer);
+    remove_from_indexes(e, &transfer);
     
-    // Olay yayınla
+    // Olay yayınla; ön görüntü karşı zincirdeki tarafın öğrenebilmesi için yayınlanır
     e.events().publish(
         ("execute_conditional_transfer", transfer.from.clone(), transfer.to.clone()),
-        (transfer.amount, transfer_id)
+        (transfer.amount, transfer_id, preimage)
     );
     
     Ok(())
-}
\ No newline at end of file
+}
+
+// Bekleyen bir transfer için onay kaydet
+pub fn approve_conditional_transfer(
+    e: &Env,
+    transfer_id: u32,
+    approver: Address
+) -> Result<(), ConditionalError> {
+    let transfer = read_conditional_transfer(e, transfer_id)
+        .ok_or(ConditionalError::TransferNotFound)?;
+
+    if transfer.executed {
+        return Err(ConditionalError::TransferAlreadyExecuted);
+    }
+    if transfer.cancelled {
+        return Err(ConditionalError::TransferCancelled);
+    }
+    if is_expired(e, &transfer) {
+        return Err(ConditionalError::TransferExpired);
+    }
+
+    // Sadece koşulda belirtilen onaylayıcılar onay verebilir
+    if !is_designated_approver(&transfer.condition, &approver) {
+        return Err(ConditionalError::NotAnApprover);
+    }
+
+    approver.require_auth();
+
+    let mut approvals = read_approvals(e, transfer_id);
+    if approvals.contains(&approver) {
+        return Err(ConditionalError::AlreadyApproved);
+    }
+    approvals.push_back(approver.clone());
+    write_approvals(e, transfer_id, &approvals);
+
+    // Olay yayınla
+    e.events().publish(
+        ("approve_conditional_transfer", approver),
+        (transfer_id, approvals.len())
+    );
+
+    Ok(())
+}
+
+// Koşullu bir transferi iptal et ve emaneti göndericiye iade et
+pub fn cancel_conditional_transfer(
+    e: &Env,
+    transfer_id: u32,
+    consenter: Option<Address>
+) -> Result<(), ConditionalError> {
+    let mut transfer = read_conditional_transfer(e, transfer_id)
+        .ok_or(ConditionalError::TransferNotFound)?;
+
+    if transfer.executed {
+        return Err(ConditionalError::TransferAlreadyExecuted);
+    }
+    if transfer.cancelled {
+        return Err(ConditionalError::TransferCancelled);
+    }
+
+    // İptali her zaman gönderici başlatır
+    transfer.from.require_auth();
+
+    // Süresi dolmadan iptal için alıcının ya da onaylayıcının rızası gerekir
+    if !is_expired(e, &transfer) {
+        match consenter {
+            Some(addr) if addr == transfer.to || is_designated_approver(&transfer.condition, &addr) => {
+                addr.require_auth();
+            },
+            _ => return Err(ConditionalError::ConsentRequired),
+        }
+    }
+
+    // Emanetteki tutarı göndericiye iade et
+    spend_balance(e, e.current_contract_address(), transfer.amount);
+    receive_balance(e, transfer.from.clone(), transfer.amount);
+    TokenUtils::new(e)
+        .events()
+        .transfer(e.current_contract_address(), transfer.from.clone(), transfer.amount);
+
+    // Transferi iptal edildi olarak işaretle
+    transfer.cancelled = true;
+    write_conditional_transfer(e, &transfer);
+    remove_from_indexes(e, &transfer);
+
+    // Olay yayınla
+    e.events().publish(
+        ("cancel_conditional_transfer", transfer.from.clone(), transfer.to.clone()),
+        (transfer.amount, transfer_id)
+    );
+
+    Ok(())
+}
+
+// Alıcı istemediği bekleyen transferi reddeder; tutar göndericiye iade edilir
+pub fn reject_conditional_transfer(e: &Env, transfer_id: u32) -> Result<(), ConditionalError> {
+    let mut transfer = read_conditional_transfer(e, transfer_id)
+        .ok_or(ConditionalError::TransferNotFound)?;
+
+    if transfer.executed {
+        return Err(ConditionalError::TransferAlreadyExecuted);
+    }
+    if transfer.cancelled {
+        return Err(ConditionalError::TransferCancelled);
+    }
+
+    transfer.to.require_auth();
+
+    // Emanetteki tutarı göndericiye iade et
+    spend_balance(e, e.current_contract_address(), transfer.amount);
+    receive_balance(e, transfer.from.clone(), transfer.amount);
+    TokenUtils::new(e)
+        .events()
+        .transfer(e.current_contract_address(), transfer.from.clone(), transfer.amount);
+
+    transfer.cancelled = true;
+    write_conditional_transfer(e, &transfer);
+    remove_from_indexes(e, &transfer);
+
+    // Olay yayınla
+    e.events().publish(
+        ("reject_conditional_transfer", transfer.to.clone(), transfer.from.clone()),
+        (transfer.amount, transfer_id)
+    );
+
+    Ok(())
+}
+
+// Koşullu transfer detaylarını al
+pub fn get_conditional_transfer(e: &Env, transfer_id: u32) -> Result<ConditionalTransfer, ConditionalError> {
+    read_conditional_transfer(e, transfer_id).ok_or(ConditionalError::TransferNotFound)
+}
+
+// Koşulun şu anki durumunu yürütmeden kontrol et
+// Sağlanmıyorsa başarısız dalın kökten itibaren indeks yolunu döndürür
+pub fn check_conditional_transfer(
+    e: &Env,
+    transfer_id: u32,
+    approver: Option<Address>,
+    preimage: Option<Bytes>
+) -> Result<Option<Vec<u32>>, ConditionalError> {
+    let transfer = read_conditional_transfer(e, transfer_id)
+        .ok_or(ConditionalError::TransferNotFound)?;
+
+    let ctx = ConditionContext {
+        current_ledger: e.ledger().sequence(),
+        approver,
+        approvals: read_approvals(e, transfer_id),
+        preimage,
+    };
+    Ok(evaluate_condition(e, &transfer.condition, &ctx).err())
+}
+
+// Bir transfer için kaydedilmiş onaylar
+pub fn get_approvals(e: &Env, transfer_id: u32) -> Vec<Address> {
+    read_approvals(e, transfer_id)
+}
+
+// Bir indeksteki bekleyen transferleri sayfalı olarak listele
+fn list_pending_transfers(e: &Env, key: DataKey, start: u32, limit: u32) -> Vec<ConditionalTransfer> {
+    let ids = read_index(e, &key);
+    let end = start.saturating_add(limit.min(MAX_PAGE_SIZE)).min(ids.len());
+
+    let mut transfers = Vec::new(e);
+    for i in start..end {
+        if let Some(transfer) = read_conditional_transfer(e, ids.get(i).unwrap()) {
+            transfers.push_back(transfer);
+        }
+    }
+    transfers
+}
+
+// Göndericinin bekleyen koşullu transferleri
+pub fn get_pending_by_sender(e: &Env, from: Address, start: u32, limit: u32) -> Vec<ConditionalTransfer> {
+    list_pending_transfers(e, DataKey::ConditionalsBySender(from), start, limit)
+}
+
+// Alıcının bekleyen koşullu transferleri
+pub fn get_pending_by_recipient(e: &Env, to: Address, start: u32, limit: u32) -> Vec<ConditionalTransfer> {
+    list_pending_transfers(e, DataKey::ConditionalsByRecipient(to), start, limit)
+}
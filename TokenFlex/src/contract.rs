@@ -1,22 +1,47 @@
+// Kontrat arayüzü ve üretilen istemci çok parametreli fonksiyonlar içerir
+#![allow(clippy::too_many_arguments)]
+
 use crate::admin::{has_administrator, read_administrator, write_administrator};
 use crate::allowance::{read_allowance, spend_allowance, write_allowance};
 use crate::balance::{read_balance, receive_balance, spend_balance};
-use crate::metadata::{read_decimal, read_name, read_symbol, write_metadata};
+use crate::metadata::{
+    read_decimal, read_max_supply, read_name, read_symbol, write_max_supply, write_metadata,
+};
 use crate::storage_types::{INSTANCE_BUMP_AMOUNT, INSTANCE_LIFETIME_THRESHOLD};
 use crate::storage_types::DataKey;
+use crate::supply::{
+    decrease_supply, increase_supply, initialize_supply, mark_supply_initialized, read_total_supply,
+};
 // Yeni modüllerin importları
 use crate::vesting::{
-    VestingType, VestingSchedule, VestingError,
-    create_vesting_schedule, claim_vested_tokens, calculate_vested_amount, get_vesting_schedule
+    VestingClock, VestingType, VestingSchedule, VestingError, VestingTimeline,
+    create_vesting_schedule, claim_vested_tokens, calculate_vested_amount, get_vesting_schedules,
+    get_vesting_timeline, accelerate_all_schedules, accelerate_vesting_schedules,
+    attest_tranche, forfeit_performance_tranche,
+    migrate_vesting_storage, pause_vesting_schedules, read_transfer_requires_admin,
+    release_vested_tokens, resume_vesting_schedules, revoke_vesting_schedules,
+    transfer_vesting_schedules, write_transfer_requires_admin
 };
 use crate::batch::{batch_transfer, BatchError};
 use crate::conditional::{
-    ConditionType, ConditionalError,
-    create_conditional_transfer, execute_conditional_transfer
+    ConditionType, ConditionalError, ConditionalTransfer,
+    approve_conditional_transfer, cancel_conditional_transfer, create_conditional_transfer,
+    check_conditional_transfer, execute_conditional_transfer, get_approvals,
+    get_conditional_transfer,
+    get_pending_by_recipient, get_pending_by_sender, reject_conditional_transfer
+};
+use crate::milestone::{
+    MilestoneError, MilestoneEscrow, MilestoneTranche,
+    approve_milestone, cancel_milestone_escrow, create_milestone_escrow, get_milestone_escrow,
+    release_milestone
+};
+use crate::escrow::{
+    ArbiterEscrow, EscrowError,
+    create_escrow, dispute_escrow, get_escrow, refund_escrow, release_escrow, resolve_dispute
 };
 
 use soroban_sdk::token::{self, Interface as _};
-use soroban_sdk::{contract, contractimpl, Address, Env, String, Vec};
+use soroban_sdk::{contract, contractimpl, Address, Bytes, Env, String, Vec};
 use soroban_token_sdk::metadata::TokenMetadata;
 use soroban_token_sdk::TokenUtils;
 
@@ -42,7 +67,14 @@ pub struct Token;
 
 #[contractimpl]
 impl Token {
-    pub fn initialize(e: Env, admin: Address, decimal: u32, name: String, symbol: String) {
+    pub fn initialize(
+        e: Env,
+        admin: Address,
+        decimal: u32,
+        name: String,
+        symbol: String,
+        max_supply: Option<i128>,
+    ) {
         if has_administrator(&e) {
             panic!("already initialized")
         }
@@ -51,6 +83,15 @@ impl Token {
             panic!("Decimal must fit in a u8");
         }
 
+        // Arz üst sınırı verildiyse metadata ile birlikte sakla
+        if let Some(max_supply) = max_supply {
+            if max_supply <= 0 {
+                panic!("Max supply must be positive");
+            }
+            write_max_supply(&e, max_supply);
+        }
+        mark_supply_initialized(&e);
+
         write_metadata(
             &e,
             TokenMetadata {
@@ -71,9 +112,48 @@ impl Token {
             .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
 
         receive_balance(&e, to.clone(), amount);
+        increase_supply(&e, amount);
         TokenUtils::new(&e).events().mint(admin, to, amount);
     }
 
+    // Arz sayacı eklenmeden önce kurulmuş kontratlarda mevcut arzı kaydet (sadece yönetici, tek seferlik)
+    pub fn set_initial_supply(e: Env, amount: i128) {
+        check_nonnegative_amount(amount);
+        let admin = read_administrator(&e);
+        admin.require_auth();
+
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        initialize_supply(&e, amount);
+        e.events().publish(("set_initial_supply", admin), amount);
+    }
+
+    // Toplam token arzı
+    pub fn total_supply(e: Env) -> i128 {
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        read_total_supply(&e)
+    }
+
+    // Arz üst sınırı (tanımlanmadıysa None)
+    pub fn max_supply(e: Env) -> Option<i128> {
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        read_max_supply(&e)
+    }
+
+    // Dolaşımdaki arz: kontratın kendi tuttuğu bakiyeler (ör. vesting emaneti) hariç
+    pub fn circulating_supply(e: Env) -> i128 {
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        read_total_supply(&e) - read_balance(&e, e.current_contract_address())
+    }
+
     pub fn set_admin(e: Env, new_admin: Address) {
         let admin = read_administrator(&e);
         admin.require_auth();
@@ -127,44 +207,69 @@ impl Token {
     // Token vesting fonksiyonları
     pub fn create_vesting(
         e: Env, 
+        funder: Address,
         beneficiary: Address, 
         total_amount: i128, 
         start_ledger: u32, 
         duration_ledgers: u32,
         vesting_type: VestingType,
         steps: u32,
-        cliff_ledger: u32
-    ) -> Result<(), VestingError> {
+        cliff_ledger: u32,
+        revocable: bool,
+        clock: VestingClock
+    ) -> Result<u32, VestingError> {
         let admin = read_administrator(&e);
         admin.require_auth();
+
+        // Fonlayan hesap (yönetici ya da hazine) da onay vermeli
+        if funder != admin {
+            funder.require_auth();
+        }
         
         e.storage()
             .instance()
             .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        // Fonlayan hesabın dondurulup dondurulmadığını kontrol et
+        if is_account_frozen(&e, &funder) {
+            return Err(VestingError::AccountFrozen);
+        }
         
-        // Önce tokenleri kontrat için ayarla
-        // Kontrat, tokenler vesting olana kadar onları tutar
-        receive_balance(&e, e.current_contract_address(), total_amount);
-        
-        create_vesting_schedule(
+        let schedule_id = create_vesting_schedule(
             &e, 
             admin, 
+            funder.clone(),
             beneficiary, 
             total_amount, 
             start_ledger, 
             duration_ledgers, 
             vesting_type, 
             steps, 
-            cliff_ledger
-        )
+            cliff_ledger,
+            revocable,
+            clock
+        )?;
+
+        // Tokenleri fonlayan hesaptan kontrata aktar
+        // Kontrat, tokenler vesting olana kadar onları emanette tutar
+        spend_balance(&e, funder.clone(), total_amount);
+        receive_balance(&e, e.current_contract_address(), total_amount);
+        TokenUtils::new(&e).events().transfer(funder, e.current_contract_address(), total_amount);
+
+        Ok(schedule_id)
     }
     
-    pub fn claim_vesting(e: Env, beneficiary: Address) -> Result<i128, VestingError> {
+    // Plan belirtilmezse hak sahibinin tüm planlarından talep edilir
+    pub fn claim_vesting(
+        e: Env,
+        beneficiary: Address,
+        schedule_id: Option<u32>
+    ) -> Result<i128, VestingError> {
         e.storage()
             .instance()
             .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         
-        let claimable = claim_vested_tokens(&e, beneficiary.clone())?;
+        let claimable = claim_vested_tokens(&e, beneficiary.clone(), schedule_id)?;
         
         // Vested tokenleri kontrat adresinden hak sahibine aktar
         spend_balance(&e, e.current_contract_address(), claimable);
@@ -175,12 +280,221 @@ impl Token {
         Ok(claimable)
     }
     
-    pub fn get_vesting_info(e: Env, beneficiary: Address) -> Result<VestingSchedule, VestingError> {
-        get_vesting_schedule(&e, beneficiary)
+    // Tüm planlardan talep et ve tokenleri başka bir adrese (ör. soğuk cüzdan) gönder
+    pub fn claim_vesting_to(
+        e: Env,
+        beneficiary: Address,
+        destination: Address
+    ) -> Result<i128, VestingError> {
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        // Dondurulmuş hak sahibi tokenlerini başka adrese kaçıramaz
+        if is_account_frozen(&e, &beneficiary) || is_account_frozen(&e, &destination) {
+            return Err(VestingError::AccountFrozen);
+        }
+
+        let claimable = claim_vested_tokens(&e, beneficiary, None)?;
+
+        spend_balance(&e, e.current_contract_address(), claimable);
+        receive_balance(&e, destination.clone(), claimable);
+        TokenUtils::new(&e).events().transfer(e.current_contract_address(), destination, claimable);
+
+        Ok(claimable)
+    }
+
+    // Serbest kalan tokenleri hak sahiplerine toplu olarak gönder (herkes çağırabilir)
+    pub fn release_vested(e: Env, beneficiaries: Vec<Address>) -> Result<i128, VestingError> {
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        release_vested_tokens(&e, beneficiaries)
+    }
+
+    // İptal edilebilir planları durdur (sadece yönetici yapabilir)
+    // Hak edilmiş kısım talep edilebilir kalır, kalanı fonlayan hesaba iade edilir
+    pub fn revoke_vesting(
+        e: Env,
+        beneficiary: Address,
+        schedule_id: Option<u32>
+    ) -> Result<i128, VestingError> {
+        let admin = read_administrator(&e);
+        admin.require_auth();
+
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        revoke_vesting_schedules(&e, admin, beneficiary, schedule_id)
+    }
+
+    // Hak sahibinin planlarını yeni bir adrese devret (ör. anahtar kaybı)
+    pub fn transfer_vesting(
+        e: Env,
+        old_beneficiary: Address,
+        new_beneficiary: Address
+    ) -> Result<u32, VestingError> {
+        old_beneficiary.require_auth();
+
+        // Yapılandırıldıysa yönetici de onay vermeli
+        if read_transfer_requires_admin(&e) {
+            read_administrator(&e).require_auth();
+        }
+
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        if is_account_frozen(&e, &old_beneficiary) || is_account_frozen(&e, &new_beneficiary) {
+            return Err(VestingError::AccountFrozen);
+        }
+
+        transfer_vesting_schedules(&e, old_beneficiary, new_beneficiary)
+    }
+
+    // Plan devrinde yönetici onayının gerekip gerekmediğini ayarla (sadece yönetici yapabilir)
+    pub fn set_vesting_transfer_policy(e: Env, requires_admin: bool) {
+        let admin = read_administrator(&e);
+        admin.require_auth();
+
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        write_transfer_requires_admin(&e, requires_admin);
+    }
+
+    // Performans dilimini onayla (sadece planın onaylayıcısı yapabilir)
+    // Hak sahibinin birden fazla performans planı varsa plan belirtilmelidir
+    pub fn attest_milestone(
+        e: Env,
+        beneficiary: Address,
+        tranche_index: u32,
+        schedule_id: Option<u32>
+    ) -> Result<i128, VestingError> {
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        attest_tranche(&e, beneficiary, tranche_index, schedule_id)
+    }
+
+    // Son tarihi geçmiş onaylanmamış dilimi fonlayan hesaba iade et
+    pub fn forfeit_tranche(
+        e: Env,
+        beneficiary: Address,
+        tranche_index: u32,
+        schedule_id: Option<u32>
+    ) -> Result<i128, VestingError> {
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        forfeit_performance_tranche(&e, beneficiary, tranche_index, schedule_id)
+    }
+
+    // Kalan hak edilmemiş kısmın baz puan cinsinden bir oranını hemen serbest bırak (sadece yönetici yapabilir)
+    pub fn accelerate_vesting(
+        e: Env,
+        beneficiary: Address,
+        percent_bps: u32
+    ) -> Result<i128, VestingError> {
+        let admin = read_administrator(&e);
+        admin.require_auth();
+
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        accelerate_vesting_schedules(&e, admin, beneficiary, percent_bps)
+    }
+
+    // Tüm planları hızlandır, ör. şirket el değiştirdiğinde (sadece yönetici yapabilir)
+    // Planlar start_id'den başlayarak en fazla limit kadar işlenir
+    pub fn accelerate_all(
+        e: Env,
+        percent_bps: u32,
+        start_id: u32,
+        limit: u32
+    ) -> Result<i128, VestingError> {
+        let admin = read_administrator(&e);
+        admin.require_auth();
+
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        accelerate_all_schedules(&e, admin, percent_bps, start_id, limit)
+    }
+
+    // Planların hakediş birikimini durdur (sadece yönetici yapabilir, ör. ücretsiz izin)
+    pub fn pause_vesting(
+        e: Env,
+        beneficiary: Address,
+        schedule_id: Option<u32>
+    ) -> Result<u32, VestingError> {
+        let admin = read_administrator(&e);
+        admin.require_auth();
+
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        pause_vesting_schedules(&e, admin, beneficiary, schedule_id)
+    }
+
+    // Duraklatılmış planlara devam et (sadece yönetici yapabilir)
+    pub fn resume_vesting(
+        e: Env,
+        beneficiary: Address,
+        schedule_id: Option<u32>
+    ) -> Result<u32, VestingError> {
+        let admin = read_administrator(&e);
+        admin.require_auth();
+
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        resume_vesting_schedules(&e, admin, beneficiary, schedule_id)
+    }
+
+    // Eski sürümde instance depolamada tutulan planları kalıcı depolamaya taşı (parça parça)
+    pub fn migrate_vesting(e: Env, beneficiaries: Vec<Address>) -> Result<u32, VestingError> {
+        let admin = read_administrator(&e);
+        admin.require_auth();
+
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        migrate_vesting_storage(&e, admin, beneficiaries)
+    }
+
+    pub fn get_vesting_info(e: Env, beneficiary: Address) -> Result<Vec<VestingSchedule>, VestingError> {
+        get_vesting_schedules(&e, beneficiary)
     }
     
-    pub fn get_vested_amount(e: Env, beneficiary: Address) -> Result<i128, VestingError> {
-        calculate_vested_amount(&e, beneficiary)
+    // Verilen saat kaynağındaki planlar için örneklenmiş hakediş miktarları ve bir sonraki açılış zamanı
+    pub fn vesting_timeline(
+        e: Env,
+        beneficiary: Address,
+        from: u64,
+        to: u64,
+        points: u32,
+        clock: VestingClock
+    ) -> Result<Vec<VestingTimeline>, VestingError> {
+        get_vesting_timeline(&e, beneficiary, from, to, points, clock)
+    }
+
+    pub fn get_vested_amount(
+        e: Env,
+        beneficiary: Address,
+        schedule_id: Option<u32>
+    ) -> Result<i128, VestingError> {
+        calculate_vested_amount(&e, beneficiary, schedule_id)
     }
     
     // Toplu transfer fonksiyonu
@@ -205,7 +519,7 @@ impl Token {
         amount: i128,
         condition_type: ConditionType,
         expiration_ledger: u32
-    ) -> Result<(), ConditionalError> {
+    ) -> Result<u32, ConditionalError> {
         e.storage()
             .instance()
             .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
@@ -216,13 +530,188 @@ impl Token {
     pub fn execute_conditional(
         e: Env,
         transfer_id: u32,
-        approver: Option<Address>
+        approver: Option<Address>,
+        preimage: Option<Bytes>
     ) -> Result<(), ConditionalError> {
         e.storage()
             .instance()
             .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
             
-        execute_conditional_transfer(&e, transfer_id, approver)
+        execute_conditional_transfer(&e, transfer_id, approver, preimage)
+    }
+
+    // Çoklu onaylı transfer için onay kaydet
+    pub fn approve_conditional(
+        e: Env,
+        transfer_id: u32,
+        approver: Address
+    ) -> Result<(), ConditionalError> {
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        approve_conditional_transfer(&e, transfer_id, approver)
+    }
+
+    pub fn get_conditional_approvals(e: Env, transfer_id: u32) -> Vec<Address> {
+        get_approvals(&e, transfer_id)
+    }
+
+    // Koşullu transferi iptal et; süre dolmadıysa alıcı ya da onaylayıcı rıza vermeli
+    pub fn cancel_conditional(
+        e: Env,
+        transfer_id: u32,
+        consenter: Option<Address>
+    ) -> Result<(), ConditionalError> {
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        cancel_conditional_transfer(&e, transfer_id, consenter)
+    }
+
+    // Alıcı istemediği bekleyen koşullu transferi reddeder, tutar göndericiye döner
+    pub fn reject_conditional(e: Env, transfer_id: u32) -> Result<(), ConditionalError> {
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        reject_conditional_transfer(&e, transfer_id)
+    }
+
+    // Koşulu yürütmeden kontrol et; sağlanmıyorsa başarısız dalın yolunu döndürür
+    pub fn check_conditional(
+        e: Env,
+        transfer_id: u32,
+        approver: Option<Address>,
+        preimage: Option<Bytes>
+    ) -> Result<Option<Vec<u32>>, ConditionalError> {
+        check_conditional_transfer(&e, transfer_id, approver, preimage)
+    }
+
+    pub fn get_conditional(e: Env, transfer_id: u32) -> Result<ConditionalTransfer, ConditionalError> {
+        get_conditional_transfer(&e, transfer_id)
+    }
+
+    // Göndericinin bekleyen koşullu transferlerini sayfalı listele
+    pub fn get_conditionals_by_sender(e: Env, from: Address, start: u32, limit: u32) -> Vec<ConditionalTransfer> {
+        get_pending_by_sender(&e, from, start, limit)
+    }
+
+    // Alıcının bekleyen koşullu transferlerini sayfalı listele
+    pub fn get_conditionals_by_recipient(e: Env, to: Address, start: u32, limit: u32) -> Vec<ConditionalTransfer> {
+        get_pending_by_recipient(&e, to, start, limit)
+    }
+
+    // Kilometre taşlı emanet fonksiyonları
+    pub fn create_milestone_escrow(
+        e: Env,
+        payer: Address,
+        payee: Address,
+        tranches: Vec<MilestoneTranche>,
+        expiration_ledger: u32
+    ) -> Result<u32, MilestoneError> {
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        create_milestone_escrow(&e, payer, payee, tranches, expiration_ledger)
+    }
+
+    pub fn approve_milestone(
+        e: Env,
+        escrow_id: u32,
+        index: u32,
+        approver: Address
+    ) -> Result<(), MilestoneError> {
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        approve_milestone(&e, escrow_id, index, approver)
+    }
+
+    pub fn release_milestone(
+        e: Env,
+        escrow_id: u32,
+        index: u32,
+        approver: Option<Address>,
+        preimage: Option<Bytes>
+    ) -> Result<(), MilestoneError> {
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        release_milestone(&e, escrow_id, index, approver, preimage)
+    }
+
+    // Emaneti iptal et; serbest bırakılmamış kilometre taşları ödeyiciye iade edilir
+    pub fn cancel_milestone_escrow(
+        e: Env,
+        escrow_id: u32,
+        consenter: Option<Address>
+    ) -> Result<i128, MilestoneError> {
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        cancel_milestone_escrow(&e, escrow_id, consenter)
+    }
+
+    pub fn get_milestone_escrow(e: Env, escrow_id: u32) -> Result<MilestoneEscrow, MilestoneError> {
+        get_milestone_escrow(&e, escrow_id)
+    }
+
+    // Hakemli emanet fonksiyonları
+    pub fn create_escrow(
+        e: Env,
+        buyer: Address,
+        seller: Address,
+        arbiter: Address,
+        amount: i128
+    ) -> Result<u32, EscrowError> {
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        create_escrow(&e, buyer, seller, arbiter, amount)
+    }
+
+    pub fn release_escrow(e: Env, escrow_id: u32) -> Result<(), EscrowError> {
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        release_escrow(&e, escrow_id)
+    }
+
+    pub fn refund_escrow(e: Env, escrow_id: u32) -> Result<(), EscrowError> {
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        refund_escrow(&e, escrow_id)
+    }
+
+    pub fn dispute_escrow(e: Env, escrow_id: u32, caller: Address) -> Result<(), EscrowError> {
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        dispute_escrow(&e, escrow_id, caller)
+    }
+
+    // Hakem anlaşmazlıklı emaneti taraflar arasında paylaştırır
+    pub fn resolve_dispute(e: Env, escrow_id: u32, seller_amount: i128) -> Result<(), EscrowError> {
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        resolve_dispute(&e, escrow_id, seller_amount)
+    }
+
+    pub fn get_escrow(e: Env, escrow_id: u32) -> Result<ArbiterEscrow, EscrowError> {
+        get_escrow(&e, escrow_id)
     }
 }
 
@@ -314,6 +803,7 @@ impl token::Interface for Token {
 
         // Yakma işlemini gerçekleştir
         spend_balance(&e, from.clone(), amount);
+        decrease_supply(&e, amount);
         TokenUtils::new(&e).events().burn(from, amount);
     }
 
@@ -334,6 +824,7 @@ impl token::Interface for Token {
         // Yakma işlemini gerçekleştir
         spend_allowance(&e, from.clone(), spender, amount);
         spend_balance(&e, from.clone(), amount);
+        decrease_supply(&e, amount);
         TokenUtils::new(&e).events().burn(from, amount)
     }
 
@@ -0,0 +1,237 @@
+use soroban_sdk::{Address, Env, contracterror, contracttype};
+use crate::balance::{receive_balance, spend_balance};
+use crate::contract::is_account_frozen;
+use crate::storage_types::{DataKey, BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD};
+use soroban_token_sdk::TokenUtils;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum EscrowError {
+    InvalidAmount = 1,
+    InvalidParties = 2,
+    EscrowNotFound = 3,
+    InvalidState = 4,
+    NotAParty = 5,
+    InvalidSplit = 6,
+    AccountFrozen = 7,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum EscrowStatus {
+    Funded,    // Alıcı tutarı kilitledi
+    Disputed,  // Taraflardan biri anlaşmazlık bildirdi
+    Released,  // Alıcı tutarı satıcıya serbest bıraktı
+    Resolved,  // Hakem tutarı taraflar arasında paylaştırdı
+    Refunded,  // Satıcı tutarı alıcıya iade etti
+}
+
+#[contracttype]
+pub struct ArbiterEscrow {
+    pub id: u32,
+    pub buyer: Address,
+    pub seller: Address,
+    pub arbiter: Address,
+    pub amount: i128,
+    pub status: EscrowStatus,
+}
+
+fn read_escrow(e: &Env, escrow_id: u32) -> Result<ArbiterEscrow, EscrowError> {
+    let key = DataKey::ArbiterEscrow(escrow_id);
+    let escrow = e
+        .storage()
+        .persistent()
+        .get::<_, ArbiterEscrow>(&key)
+        .ok_or(EscrowError::EscrowNotFound)?;
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+    Ok(escrow)
+}
+
+fn write_escrow(e: &Env, escrow: &ArbiterEscrow) {
+    let key = DataKey::ArbiterEscrow(escrow.id);
+    e.storage().persistent().set(&key, escrow);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+// Sayaçtan yeni bir emanet kimliği ayır
+fn next_escrow_id(e: &Env) -> u32 {
+    let key = DataKey::ArbiterEscrowCount;
+    let escrow_id = e.storage().instance().get::<_, u32>(&key).unwrap_or(0) + 1;
+    e.storage().instance().set(&key, &escrow_id);
+    escrow_id
+}
+
+// Emanetten bir tarafa ödeme yap
+fn pay_out(e: &Env, to: &Address, amount: i128) {
+    if amount > 0 {
+        spend_balance(e, e.current_contract_address(), amount);
+        receive_balance(e, to.clone(), amount);
+        TokenUtils::new(e)
+            .events()
+            .transfer(e.current_contract_address(), to.clone(), amount);
+    }
+}
+
+fn ensure_parties_not_frozen(e: &Env, escrow: &ArbiterEscrow) -> Result<(), EscrowError> {
+    if is_account_frozen(e, &escrow.buyer) || is_account_frozen(e, &escrow.seller) {
+        return Err(EscrowError::AccountFrozen);
+    }
+    Ok(())
+}
+
+// Alıcının fonladığı hakemli bir emanet oluştur
+pub fn create_escrow(
+    e: &Env,
+    buyer: Address,
+    seller: Address,
+    arbiter: Address,
+    amount: i128
+) -> Result<u32, EscrowError> {
+    // Parametreleri doğrula
+    if amount <= 0 {
+        return Err(EscrowError::InvalidAmount);
+    }
+    if buyer == seller || arbiter == buyer || arbiter == seller {
+        return Err(EscrowError::InvalidParties);
+    }
+
+    // Yetkilendirme iste
+    buyer.require_auth();
+
+    // Alıcının hesabı dondurulmuş mu kontrol et
+    if is_account_frozen(e, &buyer) {
+        return Err(EscrowError::AccountFrozen);
+    }
+
+    // Tutarı alıcıdan kontrat emanetine aktar
+    spend_balance(e, buyer.clone(), amount);
+    receive_balance(e, e.current_contract_address(), amount);
+    TokenUtils::new(e).events().transfer(buyer.clone(), e.current_contract_address(), amount);
+
+    let escrow_id = next_escrow_id(e);
+    let escrow = ArbiterEscrow {
+        id: escrow_id,
+        buyer: buyer.clone(),
+        seller: seller.clone(),
+        arbiter: arbiter.clone(),
+        amount,
+        status: EscrowStatus::Funded,
+    };
+    write_escrow(e, &escrow);
+
+    // Olay yayınla
+    e.events().publish(
+        ("create_escrow", buyer, seller),
+        (amount, escrow_id, arbiter)
+    );
+
+    Ok(escrow_id)
+}
+
+// Alıcı tutarı satıcıya serbest bırakır (anlaşmazlık sırasında da mümkündür)
+pub fn release_escrow(e: &Env, escrow_id: u32) -> Result<(), EscrowError> {
+    let mut escrow = read_escrow(e, escrow_id)?;
+    if escrow.status != EscrowStatus::Funded && escrow.status != EscrowStatus::Disputed {
+        return Err(EscrowError::InvalidState);
+    }
+
+    escrow.buyer.require_auth();
+    ensure_parties_not_frozen(e, &escrow)?;
+
+    pay_out(e, &escrow.seller, escrow.amount);
+    escrow.status = EscrowStatus::Released;
+    write_escrow(e, &escrow);
+
+    // Olay yayınla
+    e.events().publish(
+        ("release_escrow", escrow.buyer.clone(), escrow.seller.clone()),
+        (escrow.amount, escrow_id)
+    );
+
+    Ok(())
+}
+
+// Satıcı tutarı alıcıya iade eder (anlaşmazlık sırasında da mümkündür)
+pub fn refund_escrow(e: &Env, escrow_id: u32) -> Result<(), EscrowError> {
+    let mut escrow = read_escrow(e, escrow_id)?;
+    if escrow.status != EscrowStatus::Funded && escrow.status != EscrowStatus::Disputed {
+        return Err(EscrowError::InvalidState);
+    }
+
+    escrow.seller.require_auth();
+    ensure_parties_not_frozen(e, &escrow)?;
+
+    pay_out(e, &escrow.buyer, escrow.amount);
+    escrow.status = EscrowStatus::Refunded;
+    write_escrow(e, &escrow);
+
+    // Olay yayınla
+    e.events().publish(
+        ("refund_escrow", escrow.seller.clone(), escrow.buyer.clone()),
+        (escrow.amount, escrow_id)
+    );
+
+    Ok(())
+}
+
+// Alıcı ya da satıcı anlaşmazlık bildirir
+pub fn dispute_escrow(e: &Env, escrow_id: u32, caller: Address) -> Result<(), EscrowError> {
+    let mut escrow = read_escrow(e, escrow_id)?;
+    if escrow.status != EscrowStatus::Funded {
+        return Err(EscrowError::InvalidState);
+    }
+    if caller != escrow.buyer && caller != escrow.seller {
+        return Err(EscrowError::NotAParty);
+    }
+
+    caller.require_auth();
+
+    escrow.status = EscrowStatus::Disputed;
+    write_escrow(e, &escrow);
+
+    // Olay yayınla
+    e.events().publish(
+        ("dispute_escrow", caller),
+        (escrow_id, escrow.arbiter.clone())
+    );
+
+    Ok(())
+}
+
+// Hakem anlaşmazlığı çözer; satıcıya verilmeyen kısım alıcıya iade edilir
+pub fn resolve_dispute(e: &Env, escrow_id: u32, seller_amount: i128) -> Result<(), EscrowError> {
+    let mut escrow = read_escrow(e, escrow_id)?;
+    if escrow.status != EscrowStatus::Disputed {
+        return Err(EscrowError::InvalidState);
+    }
+    if seller_amount < 0 || seller_amount > escrow.amount {
+        return Err(EscrowError::InvalidSplit);
+    }
+
+    escrow.arbiter.require_auth();
+    ensure_parties_not_frozen(e, &escrow)?;
+
+    let buyer_amount = escrow.amount - seller_amount;
+    pay_out(e, &escrow.seller, seller_amount);
+    pay_out(e, &escrow.buyer, buyer_amount);
+    escrow.status = EscrowStatus::Resolved;
+    write_escrow(e, &escrow);
+
+    // Olay yayınla
+    e.events().publish(
+        ("resolve_dispute", escrow.arbiter.clone()),
+        (escrow_id, seller_amount, buyer_amount)
+    );
+
+    Ok(())
+}
+
+// Hakemli emanet detaylarını al
+pub fn get_escrow(e: &Env, escrow_id: u32) -> Result<ArbiterEscrow, EscrowError> {
+    read_escrow(e, escrow_id)
+}
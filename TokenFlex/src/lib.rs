@@ -6,10 +6,13 @@ mod balance;
 mod contract;
 mod metadata;
 mod storage_types;
+mod supply;
 mod test;
 
 mod vesting;
 mod batch;
 mod conditional;
+mod milestone;
+mod escrow;
 
 pub use crate::contract::TokenClient;
\ No newline at end of file
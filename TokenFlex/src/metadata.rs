@@ -1,3 +1,4 @@
+use crate::storage_types::DataKey;
 use soroban_sdk::{Env, String};
 use soroban_token_sdk::{metadata::TokenMetadata, TokenUtils};
 
@@ -19,4 +20,14 @@ pub fn read_symbol(e: &Env) -> String {
 pub fn write_metadata(e: &Env, metadata: TokenMetadata) {
     let util = TokenUtils::new(e);
     util.metadata().set_metadata(&metadata);
+}
+
+pub fn read_max_supply(e: &Env) -> Option<i128> {
+    let key = DataKey::MaxSupply;
+    e.storage().instance().get(&key)
+}
+
+pub fn write_max_supply(e: &Env, max_supply: i128) {
+    let key = DataKey::MaxSupply;
+    e.storage().instance().set(&key, &max_supply);
 }
\ No newline at end of file
@@ -0,0 +1,342 @@
+use soroban_sdk::{Address, Bytes, Env, Vec, contracterror, contracttype};
+use crate::balance::{receive_balance, spend_balance};
+use crate::conditional::{
+    ConditionContext, ConditionType, contains_hash_lock, evaluate_condition,
+    is_designated_approver, validate_condition
+};
+use crate::contract::is_account_frozen;
+use crate::storage_types::{DataKey, BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD};
+use soroban_token_sdk::TokenUtils;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum MilestoneError {
+    InvalidMilestones = 1,
+    InvalidCondition = 2,
+    EscrowNotFound = 3,
+    MilestoneNotFound = 4,
+    MilestoneAlreadyReleased = 5,
+    ConditionNotMet = 6,
+    EscrowExpired = 7,
+    EscrowCancelled = 8,
+    EscrowSettled = 9,
+    ConsentRequired = 10,
+    NotAnApprover = 11,
+    AlreadyApproved = 12,
+    AccountFrozen = 13,
+}
+
+// Bir emanette tanımlanabilecek en fazla kilometre taşı
+pub const MAX_MILESTONES: u32 = 20;
+
+// Emanet oluşturulurken verilen kilometre taşı
+#[derive(Clone)]
+#[contracttype]
+pub struct MilestoneTranche {
+    pub amount: i128,
+    pub condition: ConditionType,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct Milestone {
+    pub amount: i128,
+    pub condition: ConditionType,
+    pub released: bool,
+    pub approvals: Vec<Address>,   // Bu kilometre taşı için kaydedilen onaylar
+}
+
+#[contracttype]
+pub struct MilestoneEscrow {
+    pub id: u32,
+    pub payer: Address,
+    pub payee: Address,
+    pub milestones: Vec<Milestone>,
+    pub cancelled: bool,
+    pub expiration_ledger: u32,  // Opsiyonel son kullanma tarihi
+}
+
+fn read_escrow(e: &Env, escrow_id: u32) -> Result<MilestoneEscrow, MilestoneError> {
+    let key = DataKey::MilestoneEscrow(escrow_id);
+    let escrow = e
+        .storage()
+        .persistent()
+        .get::<_, MilestoneEscrow>(&key)
+        .ok_or(MilestoneError::EscrowNotFound)?;
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+    Ok(escrow)
+}
+
+fn write_escrow(e: &Env, escrow: &MilestoneEscrow) {
+    let key = DataKey::MilestoneEscrow(escrow.id);
+    e.storage().persistent().set(&key, escrow);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+// Sayaçtan yeni bir emanet kimliği ayır
+fn next_escrow_id(e: &Env) -> u32 {
+    let key = DataKey::MilestoneEscrowCount;
+    let escrow_id = e.storage().instance().get::<_, u32>(&key).unwrap_or(0) + 1;
+    e.storage().instance().set(&key, &escrow_id);
+    escrow_id
+}
+
+fn is_expired(e: &Env, escrow: &MilestoneEscrow) -> bool {
+    escrow.expiration_ledger > 0 && e.ledger().sequence() > escrow.expiration_ledger
+}
+
+// Henüz serbest bırakılmamış toplam tutar
+fn unreleased_amount(escrow: &MilestoneEscrow) -> i128 {
+    let mut amount: i128 = 0;
+    for milestone in escrow.milestones.iter() {
+        if !milestone.released {
+            amount += milestone.amount;
+        }
+    }
+    amount
+}
+
+// Adres, serbest bırakılmamış bir kilometre taşında onaylayıcı mı
+fn is_pending_approver(escrow: &MilestoneEscrow, addr: &Address) -> bool {
+    escrow
+        .milestones
+        .iter()
+        .any(|milestone| !milestone.released && is_designated_approver(&milestone.condition, addr))
+}
+
+// Emanet hâlâ açık mı kontrol et
+fn ensure_open(e: &Env, escrow: &MilestoneEscrow) -> Result<(), MilestoneError> {
+    if escrow.cancelled {
+        return Err(MilestoneError::EscrowCancelled);
+    }
+    if is_expired(e, escrow) {
+        return Err(MilestoneError::EscrowExpired);
+    }
+    Ok(())
+}
+
+fn read_milestone(escrow: &MilestoneEscrow, index: u32) -> Result<Milestone, MilestoneError> {
+    let milestone = escrow
+        .milestones
+        .get(index)
+        .ok_or(MilestoneError::MilestoneNotFound)?;
+    if milestone.released {
+        return Err(MilestoneError::MilestoneAlreadyReleased);
+    }
+    Ok(milestone)
+}
+
+// Kilometre taşlı bir emanet oluştur ve toplam tutarı kilitle
+pub fn create_milestone_escrow(
+    e: &Env,
+    payer: Address,
+    payee: Address,
+    tranches: Vec<MilestoneTranche>,
+    expiration_ledger: u32
+) -> Result<u32, MilestoneError> {
+    // Parametreleri doğrula
+    if tranches.is_empty() || tranches.len() > MAX_MILESTONES {
+        return Err(MilestoneError::InvalidMilestones);
+    }
+
+    let mut total_amount: i128 = 0;
+    let mut milestones = Vec::new(e);
+    for tranche in tranches.iter() {
+        if tranche.amount <= 0 {
+            return Err(MilestoneError::InvalidMilestones);
+        }
+        validate_condition(&tranche.condition, 1).map_err(|_| MilestoneError::InvalidCondition)?;
+        // Hash kilitli kilometre taşları iade için bir son kullanma tarihine ihtiyaç duyar
+        if contains_hash_lock(&tranche.condition) && expiration_ledger == 0 {
+            return Err(MilestoneError::InvalidCondition);
+        }
+
+        total_amount = total_amount
+            .checked_add(tranche.amount)
+            .ok_or(MilestoneError::InvalidMilestones)?;
+        milestones.push_back(Milestone {
+            amount: tranche.amount,
+            condition: tranche.condition,
+            released: false,
+            approvals: Vec::new(e),
+        });
+    }
+
+    // Yetkilendirme iste
+    payer.require_auth();
+
+    // Ödeyicinin hesabı dondurulmuş mu kontrol et
+    if is_account_frozen(e, &payer) {
+        return Err(MilestoneError::AccountFrozen);
+    }
+
+    // Toplam tutarı ödeyiciden kontrat emanetine aktar
+    spend_balance(e, payer.clone(), total_amount);
+    receive_balance(e, e.current_contract_address(), total_amount);
+    TokenUtils::new(e).events().transfer(payer.clone(), e.current_contract_address(), total_amount);
+
+    let escrow_id = next_escrow_id(e);
+    let escrow = MilestoneEscrow {
+        id: escrow_id,
+        payer: payer.clone(),
+        payee: payee.clone(),
+        milestones,
+        cancelled: false,
+        expiration_ledger,
+    };
+    write_escrow(e, &escrow);
+
+    // Olay yayınla
+    e.events().publish(
+        ("create_milestone_escrow", payer, payee),
+        (total_amount, escrow_id)
+    );
+
+    Ok(escrow_id)
+}
+
+// Bir kilometre taşı için onay kaydet
+pub fn approve_milestone(
+    e: &Env,
+    escrow_id: u32,
+    index: u32,
+    approver: Address
+) -> Result<(), MilestoneError> {
+    let mut escrow = read_escrow(e, escrow_id)?;
+    ensure_open(e, &escrow)?;
+    let mut milestone = read_milestone(&escrow, index)?;
+
+    // Sadece kilometre taşının koşulunda belirtilen onaylayıcılar onay verebilir
+    if !is_designated_approver(&milestone.condition, &approver) {
+        return Err(MilestoneError::NotAnApprover);
+    }
+
+    approver.require_auth();
+
+    if milestone.approvals.contains(&approver) {
+        return Err(MilestoneError::AlreadyApproved);
+    }
+    milestone.approvals.push_back(approver.clone());
+    escrow.milestones.set(index, milestone);
+    write_escrow(e, &escrow);
+
+    // Olay yayınla
+    e.events().publish(
+        ("approve_milestone", approver),
+        (escrow_id, index)
+    );
+
+    Ok(())
+}
+
+// Koşulu sağlanan kilometre taşının tutarını alıcıya öde
+pub fn release_milestone(
+    e: &Env,
+    escrow_id: u32,
+    index: u32,
+    approver: Option<Address>,
+    preimage: Option<Bytes>
+) -> Result<(), MilestoneError> {
+    let mut escrow = read_escrow(e, escrow_id)?;
+    ensure_open(e, &escrow)?;
+    let mut milestone = read_milestone(&escrow, index)?;
+
+    // Kilometre taşının koşulunu değerlendir
+    let ctx = ConditionContext {
+        current_ledger: e.ledger().sequence(),
+        approver: approver.clone(),
+        approvals: milestone.approvals.clone(),
+        preimage: preimage.clone(),
+    };
+    if evaluate_condition(e, &milestone.condition, &ctx).is_err() {
+        return Err(MilestoneError::ConditionNotMet);
+    }
+
+    // Onaylayıcı yetkilendirmesi iste
+    if let Some(addr) = approver {
+        addr.require_auth();
+    }
+
+    // Taraflardan biri dondurulmuşsa emanet serbest bırakılamaz
+    if is_account_frozen(e, &escrow.payer) || is_account_frozen(e, &escrow.payee) {
+        return Err(MilestoneError::AccountFrozen);
+    }
+
+    // Kilometre taşı tutarını alıcıya öde
+    spend_balance(e, e.current_contract_address(), milestone.amount);
+    receive_balance(e, escrow.payee.clone(), milestone.amount);
+    TokenUtils::new(e)
+        .events()
+        .transfer(e.current_contract_address(), escrow.payee.clone(), milestone.amount);
+
+    milestone.released = true;
+    let amount = milestone.amount;
+    escrow.milestones.set(index, milestone);
+    write_escrow(e, &escrow);
+
+    // Olay yayınla
+    e.events().publish(
+        ("release_milestone", escrow.payer.clone(), escrow.payee.clone()),
+        (amount, escrow_id, index, preimage)
+    );
+
+    Ok(())
+}
+
+// Emaneti iptal et ve serbest bırakılmamış kilometre taşlarını ödeyiciye iade et
+pub fn cancel_milestone_escrow(
+    e: &Env,
+    escrow_id: u32,
+    consenter: Option<Address>
+) -> Result<i128, MilestoneError> {
+    let mut escrow = read_escrow(e, escrow_id)?;
+
+    if escrow.cancelled {
+        return Err(MilestoneError::EscrowCancelled);
+    }
+    let refund = unreleased_amount(&escrow);
+    if refund == 0 {
+        return Err(MilestoneError::EscrowSettled);
+    }
+
+    // İptali her zaman ödeyici başlatır
+    escrow.payer.require_auth();
+
+    // Süresi dolmadan iptal için alıcının ya da bir onaylayıcının rızası gerekir
+    if !is_expired(e, &escrow) {
+        match consenter {
+            Some(addr) if addr == escrow.payee || is_pending_approver(&escrow, &addr) => {
+                addr.require_auth();
+            },
+            _ => return Err(MilestoneError::ConsentRequired),
+        }
+    }
+
+    // Serbest bırakılmamış tutarı ödeyiciye iade et
+    spend_balance(e, e.current_contract_address(), refund);
+    receive_balance(e, escrow.payer.clone(), refund);
+    TokenUtils::new(e)
+        .events()
+        .transfer(e.current_contract_address(), escrow.payer.clone(), refund);
+
+    escrow.cancelled = true;
+    write_escrow(e, &escrow);
+
+    // Olay yayınla
+    e.events().publish(
+        ("cancel_milestone_escrow", escrow.payer.clone(), escrow.payee.clone()),
+        (refund, escrow_id)
+    );
+
+    Ok(refund)
+}
+
+// Kilometre taşlı emanet detaylarını al
+pub fn get_milestone_escrow(e: &Env, escrow_id: u32) -> Result<MilestoneEscrow, MilestoneError> {
+    read_escrow(e, escrow_id)
+}
@@ -29,8 +29,22 @@ pub enum DataKey {
     State(Address),
     Admin,
     Frozen(Address),
-    VestingSchedule(Address),
+    VestingSchedule(u32),
+    VestingScheduleCount,
+    VestingSchedules(Address),
+    VestingTransferRequiresAdmin,
     ConditionalTransfer(u32),
+    ConditionalTransferCount,
+    ConditionalsBySender(Address),
+    ConditionalsByRecipient(Address),
+    ConditionalApprovals(u32),
+    MilestoneEscrow(u32),
+    MilestoneEscrowCount,
+    ArbiterEscrow(u32),
+    ArbiterEscrowCount,
     LiquidityPool(Address), 
     RecurringPayment(Address),
+    TotalSupply,
+    MaxSupply,
+    SupplyInitialized,
 }
\ No newline at end of file
@@ -0,0 +1,67 @@
+use soroban_sdk::{Env, contracterror, panic_with_error};
+use crate::metadata::read_max_supply;
+use crate::storage_types::DataKey;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum SupplyError {
+    MaxSupplyExceeded = 1,
+    SupplyAlreadyInitialized = 2,
+}
+
+// Dolaşımdaki toplam token miktarını oku
+pub fn read_total_supply(e: &Env) -> i128 {
+    let key = DataKey::TotalSupply;
+    e.storage().instance().get::<_, i128>(&key).unwrap_or(0)
+}
+
+fn write_total_supply(e: &Env, amount: i128) {
+    let key = DataKey::TotalSupply;
+    e.storage().instance().set(&key, &amount);
+}
+
+// Yeni basılan tokenleri toplam arza ekle, üst sınır aşılamaz
+pub fn increase_supply(e: &Env, amount: i128) {
+    let supply = read_total_supply(e);
+    let new_supply = supply
+        .checked_add(amount)
+        .unwrap_or_else(|| panic_with_error!(e, SupplyError::MaxSupplyExceeded));
+
+    if let Some(max_supply) = read_max_supply(e) {
+        if new_supply > max_supply {
+            panic_with_error!(e, SupplyError::MaxSupplyExceeded);
+        }
+    }
+    write_total_supply(e, new_supply);
+}
+
+// Yeni kurulan kontratta arz sayacı baştan doğrudur, başlangıç arzı ayarlanamaz
+pub fn mark_supply_initialized(e: &Env) {
+    e.storage().instance().set(&DataKey::SupplyInitialized, &true);
+}
+
+// Arz sayacından önce kurulmuş kontratlarda mevcut bakiyelerin toplamını sayaca ekle (tek seferlik)
+// Miktar vesting emaneti dahil tüm bakiyeleri kapsamalı; yoksa eski tokenler yakılamaz
+pub fn initialize_supply(e: &Env, amount: i128) {
+    let key = DataKey::SupplyInitialized;
+    if e.storage().instance().has(&key) {
+        panic_with_error!(e, SupplyError::SupplyAlreadyInitialized);
+    }
+
+    let supply = read_total_supply(e);
+    let new_supply = supply
+        .checked_add(amount)
+        .unwrap_or_else(|| panic_with_error!(e, SupplyError::MaxSupplyExceeded));
+    write_total_supply(e, new_supply);
+    e.storage().instance().set(&key, &true);
+}
+
+// Yakılan tokenleri toplam arzdan düş
+pub fn decrease_supply(e: &Env, amount: i128) {
+    let supply = read_total_supply(e);
+    if supply < amount {
+        panic!("insufficient supply");
+    }
+    write_total_supply(e, supply - amount);
+}
@@ -1,16 +1,31 @@
 #![cfg(test)]
 extern crate std;
 
-use crate::{contract::Token, TokenClient};
+use crate::{
+    conditional::{
+        ConditionType, ConditionalError, ContractCheckCondition, MultiApprovalCondition,
+        MAX_PENDING_PER_RECIPIENT,
+    },
+    contract::Token,
+    escrow::{EscrowError, EscrowStatus},
+    milestone::{MilestoneError, MilestoneTranche},
+    storage_types::DataKey,
+    supply::SupplyError,
+    vesting::{
+        PerformanceCondition, PerformanceTranche, UnlockPoint, VestingClock, VestingError,
+        VestingTimeline, VestingType, MAX_ACCELERATIONS,
+    },
+    TokenClient,
+};
 use soroban_sdk::{
-    symbol_short,
-    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation},
-    Address, Env, IntoVal, Symbol,
+    contract, contractimpl, contracttype, symbol_short,
+    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events, Ledger},
+    vec, Address, Bytes, BytesN, Env, IntoVal, Symbol,
 };
 
 fn create_token<'a>(e: &Env, admin: &Address) -> TokenClient<'a> {
-    let token = TokenClient::new(e, &e.register_contract(None, Token {}));
-    token.initialize(admin, &7, &"name".into_val(e), &"symbol".into_val(e));
+    let token = TokenClient::new(e, &e.register(Token, ()));
+    token.initialize(admin, &7, &"name".into_val(e), &"symbol".into_val(e), &None);
     token
 }
 
@@ -238,7 +253,7 @@ fn initialize_already_initialized() {
     let admin = Address::generate(&e);
     let token = create_token(&e, &admin);
 
-    token.initialize(&admin, &10, &"name".into_val(&e), &"symbol".into_val(&e));
+    token.initialize(&admin, &10, &"name".into_val(&e), &"symbol".into_val(&e), &None);
 }
 
 #[test]
@@ -246,11 +261,1717 @@ fn initialize_already_initialized() {
 fn decimal_is_over_max() {
     let e = Env::default();
     let admin = Address::generate(&e);
-    let token = TokenClient::new(&e, &e.register_contract(None, Token {}));
+    let token = TokenClient::new(&e, &e.register(Token, ()));
     token.initialize(
         &admin,
         &(u32::from(u8::MAX) + 1),
         &"name".into_val(&e),
         &"symbol".into_val(&e),
+        &None,
+    );
+}
+
+#[test]
+fn test_total_supply() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    assert_eq!(token.total_supply(), 0);
+
+    token.mint(&user1, &1000);
+    token.mint(&user2, &500);
+    assert_eq!(token.total_supply(), 1500);
+
+    token.burn(&user1, &200);
+    assert_eq!(token.total_supply(), 1300);
+
+    token.approve(&user2, &user1, &100, &200);
+    token.burn_from(&user1, &user2, &100);
+    assert_eq!(token.total_supply(), 1200);
+    assert_eq!(token.circulating_supply(), 1200);
+
+    // Vesting emanetindeki tokenler dolaşımda sayılmaz
+    token.create_vesting(&user1, &user2, &300, &0, &100, &VestingType::Linear, &0, &0, &false, &VestingClock::Ledger);
+    assert_eq!(token.total_supply(), 1200);
+    assert_eq!(token.circulating_supply(), 900);
+}
+
+#[test]
+fn test_set_initial_supply_after_upgrade() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    // Yeni kurulan kontratta başlangıç arzı ayarlanamaz
+    assert_eq!(
+        token.try_set_initial_supply(&100),
+        Err(Ok(SupplyError::SupplyAlreadyInitialized.into()))
+    );
+
+    // Arz sayacı olmadan basılmış bakiyeleri olan eski bir kontratı taklit et
+    token.mint(&user, &1000);
+    e.as_contract(&token.address, || {
+        e.storage().instance().remove(&DataKey::TotalSupply);
+        e.storage().instance().remove(&DataKey::SupplyInitialized);
+    });
+    assert_eq!(token.total_supply(), 0);
+
+    // Yükseltmeden sonra basılanlar sayılır, eski bakiyeler tek seferde eklenir
+    token.mint(&user, &200);
+    token.set_initial_supply(&1000);
+    assert_eq!(token.total_supply(), 1200);
+    token.burn(&user, &1100);
+    assert_eq!(token.total_supply(), 100);
+
+    assert_eq!(
+        token.try_set_initial_supply(&1000),
+        Err(Ok(SupplyError::SupplyAlreadyInitialized.into()))
+    );
+}
+
+#[test]
+fn test_max_supply() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let token = TokenClient::new(&e, &e.register(Token, ()));
+    token.initialize(&admin, &7, &"name".into_val(&e), &"symbol".into_val(&e), &Some(1000));
+    assert_eq!(token.max_supply(), Some(1000));
+
+    token.mint(&user, &1000);
+    assert_eq!(token.total_supply(), 1000);
+
+    // Yakılan miktar tekrar basılabilir
+    token.burn(&user, &100);
+    token.mint(&user, &100);
+    assert_eq!(token.total_supply(), 1000);
+
+    assert!(token.try_mint(&user, &1).is_err());
+    assert_eq!(token.total_supply(), 1000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+fn mint_over_max_supply() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let token = TokenClient::new(&e, &e.register(Token, ()));
+    token.initialize(&admin, &7, &"name".into_val(&e), &"symbol".into_val(&e), &Some(1000));
+
+    token.mint(&user, &1001);
+}
+
+#[test]
+fn test_vesting_funded_by_treasury() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let treasury = Address::generate(&e);
+    let beneficiary = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.mint(&treasury, &1000);
+    token.create_vesting(&treasury, &beneficiary, &400, &0, &100, &VestingType::Linear, &0, &0, &false, &VestingClock::Ledger);
+    assert_eq!(token.balance(&treasury), 600);
+    assert_eq!(token.balance(&token.address), 400);
+    assert_eq!(token.total_supply(), 1000);
+
+    e.ledger().set_sequence_number(50);
+    assert_eq!(token.claim_vesting(&beneficiary, &None), 200);
+    assert_eq!(token.balance(&beneficiary), 200);
+    assert_eq!(token.balance(&token.address), 200);
+
+    // Dondurulmuş hesap vesting fonlayamaz
+    token.freeze_account(&treasury);
+    assert_eq!(
+        token.try_create_vesting(&treasury, &admin, &100, &0, &100, &VestingType::Linear, &0, &0, &false, &VestingClock::Ledger),
+        Err(Ok(VestingError::AccountFrozen))
+    );
+}
+
+#[test]
+#[should_panic(expected = "insufficient balance")]
+fn vesting_funder_insufficient_balance() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let beneficiary = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.mint(&admin, &100);
+    token.create_vesting(&admin, &beneficiary, &101, &0, &100, &VestingType::Linear, &0, &0, &false, &VestingClock::Ledger);
+}
+
+#[test]
+fn test_conditional_transfer_escrow() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.mint(&user1, &1000);
+
+    e.ledger().set_sequence_number(10);
+    let id = token.create_conditional(&user1, &user2, &300, &ConditionType::TimeBasedRelease(20), &0);
+    assert_eq!(token.balance(&user1), 700);
+    assert_eq!(token.balance(&token.address), 300);
+
+    assert_eq!(
+        token.try_execute_conditional(&id, &None, &None),
+        Err(Ok(ConditionalError::ConditionNotMet))
+    );
+
+    e.ledger().set_sequence_number(20);
+    token.execute_conditional(&id, &None, &None);
+    assert_eq!(token.balance(&user2), 300);
+    assert_eq!(token.balance(&token.address), 0);
+
+    assert_eq!(
+        token.try_execute_conditional(&id, &None, &None),
+        Err(Ok(ConditionalError::TransferAlreadyExecuted))
+    );
+}
+
+#[test]
+fn test_conditional_transfer_frozen() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.mint(&user1, &1000);
+
+    e.ledger().set_sequence_number(10);
+    let id = token.create_conditional(&user1, &user2, &300, &ConditionType::ApprovalRequired(admin.clone()), &0);
+
+    // Dondurulmuş taraflar emaneti ne kilitleyebilir ne de serbest bırakabilir
+    token.freeze_account(&user2);
+    assert_eq!(
+        token.try_execute_conditional(&id, &Some(admin.clone()), &None),
+        Err(Ok(ConditionalError::AccountFrozen))
+    );
+    token.unfreeze_account(&user2);
+
+    token.freeze_account(&user1);
+    assert_eq!(
+        token.try_create_conditional(&user1, &user2, &100, &ConditionType::TimeBasedRelease(0), &0),
+        Err(Ok(ConditionalError::AccountFrozen))
+    );
+    token.unfreeze_account(&user1);
+
+    token.execute_conditional(&id, &Some(admin.clone()), &None);
+    assert_eq!(token.balance(&user1), 700);
+    assert_eq!(token.balance(&user2), 300);
+}
+
+#[test]
+fn test_conditional_transfer_ids_and_lookup() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let user3 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.mint(&user1, &1000);
+
+    // Aynı ledger içinde oluşturulan transferler birbirinin üzerine yazılmaz
+    let id1 = token.create_conditional(&user1, &user2, &100, &ConditionType::TimeBasedRelease(0), &0);
+    let id2 = token.create_conditional(&user1, &user3, &200, &ConditionType::TimeBasedRelease(0), &0);
+    let id3 = token.create_conditional(&user1, &user2, &300, &ConditionType::TimeBasedRelease(0), &0);
+    assert_eq!((id1, id2, id3), (1, 2, 3));
+    assert_eq!(token.get_conditional(&id2).amount, 200);
+    assert_eq!(
+        token.try_get_conditional(&4).err(),
+        Some(Ok(ConditionalError::TransferNotFound))
+    );
+
+    let by_sender = token.get_conditionals_by_sender(&user1, &0, &10);
+    assert_eq!(by_sender.len(), 3);
+
+    let page = token.get_conditionals_by_sender(&user1, &1, &1);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().id, id2);
+    assert_eq!(token.get_conditionals_by_sender(&user1, &5, &10).len(), 0);
+
+    let by_recipient = token.get_conditionals_by_recipient(&user2, &0, &10);
+    assert_eq!(by_recipient.len(), 2);
+    assert_eq!(by_recipient.get(1).unwrap().id, id3);
+
+    // Yürütülen transferler bekleyen listelerden düşer
+    token.execute_conditional(&id1, &None, &None);
+    assert_eq!(token.get_conditionals_by_sender(&user1, &0, &10).len(), 2);
+    let by_recipient = token.get_conditionals_by_recipient(&user2, &0, &10);
+    assert_eq!(by_recipient.len(), 1);
+    assert_eq!(by_recipient.get(0).unwrap().id, id3);
+}
+
+#[test]
+fn test_cancel_conditional_transfer() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let approver = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.mint(&user1, &1000);
+
+    e.ledger().set_sequence_number(10);
+    let id1 = token.create_conditional(&user1, &user2, &300, &ConditionType::ApprovalRequired(approver.clone()), &100);
+    let id2 = token.create_conditional(&user1, &user2, &200, &ConditionType::TimeBasedRelease(50), &100);
+    assert_eq!(token.balance(&user1), 500);
+
+    // Süre dolmadan göndericinin tek başına iptali reddedilir
+    assert_eq!(
+        token.try_cancel_conditional(&id1, &None),
+        Err(Ok(ConditionalError::ConsentRequired))
+    );
+    let stranger = Address::generate(&e);
+    assert_eq!(
+        token.try_cancel_conditional(&id1, &Some(stranger)),
+        Err(Ok(ConditionalError::ConsentRequired))
+    );
+
+    // Onaylayıcı rızasıyla iptal
+    token.cancel_conditional(&id1, &Some(approver.clone()));
+    assert_eq!(token.balance(&user1), 800);
+    assert_eq!(token.balance(&token.address), 200);
+    assert_eq!(
+        token.try_execute_conditional(&id1, &Some(approver.clone()), &None),
+        Err(Ok(ConditionalError::TransferCancelled))
+    );
+    assert_eq!(token.get_conditionals_by_sender(&user1, &0, &10).len(), 1);
+
+    // Süre dolduktan sonra gönderici tek başına iptal edebilir
+    e.ledger().set_sequence_number(101);
+    assert_eq!(
+        token.try_execute_conditional(&id2, &None, &None),
+        Err(Ok(ConditionalError::TransferExpired))
+    );
+    token.cancel_conditional(&id2, &None);
+    assert_eq!(token.balance(&user1), 1000);
+    assert_eq!(token.balance(&token.address), 0);
+    assert_eq!(
+        token.try_cancel_conditional(&id2, &None),
+        Err(Ok(ConditionalError::TransferCancelled))
+    );
+}
+
+#[test]
+fn test_cancel_conditional_with_recipient_consent() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.mint(&user1, &1000);
+    let id = token.create_conditional(&user1, &user2, &300, &ConditionType::TimeBasedRelease(0), &0);
+
+    token.cancel_conditional(&id, &Some(user2.clone()));
+    assert_eq!(
+        e.auths(),
+        std::vec![
+            (
+                user1.clone(),
+                AuthorizedInvocation {
+                    function: AuthorizedFunction::Contract((
+                        token.address.clone(),
+                        Symbol::new(&e, "cancel_conditional"),
+                        (id, Some(user2.clone())).into_val(&e),
+                    )),
+                    sub_invocations: std::vec![]
+                }
+            ),
+            (
+                user2.clone(),
+                AuthorizedInvocation {
+                    function: AuthorizedFunction::Contract((
+                        token.address.clone(),
+                        Symbol::new(&e, "cancel_conditional"),
+                        (id, Some(user2.clone())).into_val(&e),
+                    )),
+                    sub_invocations: std::vec![]
+                }
+            )
+        ]
+    );
+    assert_eq!(token.balance(&user1), 1000);
+    assert_eq!(
+        token.try_execute_conditional(&id, &None, &None),
+        Err(Ok(ConditionalError::TransferCancelled))
+    );
+}
+
+#[test]
+fn test_reject_conditional_and_recipient_cap() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let spammer = Address::generate(&e);
+    let victim = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.mint(&spammer, &1000);
+
+    // Alıcının bekleyen transfer indeksi sınırlıdır
+    let condition = ConditionType::TimeBasedRelease(1_000_000);
+    for _ in 0..MAX_PENDING_PER_RECIPIENT {
+        token.create_conditional(&spammer, &victim, &1, &condition, &0);
+    }
+    assert_eq!(
+        token.try_create_conditional(&spammer, &victim, &1, &condition, &0),
+        Err(Ok(ConditionalError::TooManyPending))
+    );
+
+    // Alıcı istemediği transferi tek başına reddedebilir
+    token.reject_conditional(&1);
+    let signers: std::vec::Vec<Address> = e.auths().into_iter().map(|(addr, _)| addr).collect();
+    assert_eq!(signers, std::vec![victim.clone()]);
+    assert_eq!(token.balance(&spammer), 1000 - MAX_PENDING_PER_RECIPIENT as i128 + 1);
+    assert_eq!(
+        token.try_reject_conditional(&1),
+        Err(Ok(ConditionalError::TransferCancelled))
+    );
+    assert_eq!(token.get_conditionals_by_recipient(&victim, &0, &50).get(0).unwrap().id, 2);
+
+    token.create_conditional(&spammer, &victim, &1, &condition, &0);
+}
+
+#[test]
+fn test_conditional_multi_approval() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let approver1 = Address::generate(&e);
+    let approver2 = Address::generate(&e);
+    let approver3 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.mint(&user1, &1000);
+
+    let condition = ConditionType::MultiApproval(MultiApprovalCondition {
+        approvers: vec![&e, approver1.clone(), approver2.clone(), approver3.clone()],
+        threshold: 2,
+    });
+    let id = token.create_conditional(&user1, &user2, &300, &condition, &0);
+
+    token.approve_conditional(&id, &approver1);
+    assert_eq!(
+        token.try_approve_conditional(&id, &approver1),
+        Err(Ok(ConditionalError::AlreadyApproved))
+    );
+    assert_eq!(
+        token.try_approve_conditional(&id, &user2),
+        Err(Ok(ConditionalError::NotAnApprover))
+    );
+    assert_eq!(
+        token.try_execute_conditional(&id, &None, &None),
+        Err(Ok(ConditionalError::ConditionNotMet))
+    );
+
+    token.approve_conditional(&id, &approver3);
+    assert_eq!(
+        token.get_conditional_approvals(&id),
+        vec![&e, approver1.clone(), approver3.clone()]
+    );
+
+    token.execute_conditional(&id, &None, &None);
+    assert_eq!(token.balance(&user2), 300);
+    assert_eq!(token.balance(&token.address), 0);
+}
+
+#[test]
+fn test_conditional_multi_approval_invalid() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let approver = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.mint(&user1, &1000);
+
+    for condition in [
+        MultiApprovalCondition { approvers: vec![&e, approver.clone()], threshold: 0 },
+        MultiApprovalCondition { approvers: vec![&e, approver.clone()], threshold: 2 },
+        MultiApprovalCondition { approvers: vec![&e, approver.clone(), approver.clone()], threshold: 2 },
+    ] {
+        assert_eq!(
+            token.try_create_conditional(&user1, &user2, &100, &ConditionType::MultiApproval(condition), &0),
+            Err(Ok(ConditionalError::InvalidCondition))
+        );
+    }
+    assert_eq!(token.balance(&user1), 1000);
+}
+
+#[test]
+fn test_conditional_condition_tree() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let legal = Address::generate(&e);
+    let approver = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.mint(&user1, &1000);
+    e.ledger().set_sequence_number(10);
+
+    // "X ledger'ından sonra VE hukuk onayı"
+    let all = ConditionType::All(vec![
+        &e,
+        ConditionType::TimeBasedRelease(20),
+        ConditionType::ApprovalRequired(legal.clone()),
+    ]);
+    let id1 = token.create_conditional(&user1, &user2, &100, &all, &0);
+
+    assert_eq!(token.check_conditional(&id1, &None, &None), Some(vec![&e, 0]));
+    e.ledger().set_sequence_number(20);
+    assert_eq!(token.check_conditional(&id1, &None, &None), Some(vec![&e, 1]));
+    assert_eq!(
+        token.try_execute_conditional(&id1, &None, &None),
+        Err(Ok(ConditionalError::ConditionNotMet))
     );
-}
\ No newline at end of file
+    assert_eq!(token.check_conditional(&id1, &Some(legal.clone()), &None), None);
+    token.execute_conditional(&id1, &Some(legal.clone()), &None);
+    assert_eq!(token.balance(&user2), 100);
+
+    // "A onayı VEYA Y ledger'ından sonra", Not ile iç içe
+    let any = ConditionType::Any(vec![
+        &e,
+        ConditionType::ApprovalRequired(approver.clone()),
+        ConditionType::Not(vec![&e, ConditionType::TimeBasedRelease(50)]),
+    ]);
+    let id2 = token.create_conditional(&user1, &user2, &100, &any, &0);
+    e.ledger().set_sequence_number(50);
+    assert_eq!(token.check_conditional(&id2, &None, &None), Some(vec![&e]));
+
+    // Önceden kaydedilen onay da yaprağı sağlar
+    token.approve_conditional(&id2, &approver);
+    token.execute_conditional(&id2, &None, &None);
+    assert_eq!(token.balance(&user2), 200);
+}
+
+#[test]
+fn test_conditional_condition_tree_invalid() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.mint(&user1, &1000);
+
+    let leaf = ConditionType::TimeBasedRelease(0);
+    let mut too_deep = leaf.clone();
+    for _ in 0..4 {
+        too_deep = ConditionType::All(vec![&e, too_deep]);
+    }
+
+    for condition in [
+        ConditionType::All(vec![&e]),
+        ConditionType::Any(vec![&e]),
+        ConditionType::Not(vec![&e, leaf.clone(), leaf.clone()]),
+        too_deep,
+    ] {
+        assert_eq!(
+            token.try_create_conditional(&user1, &user2, &100, &condition, &0),
+            Err(Ok(ConditionalError::InvalidCondition))
+        );
+    }
+
+    let mut deepest = leaf;
+    for _ in 0..3 {
+        deepest = ConditionType::Not(vec![&e, deepest]);
+    }
+    token.create_conditional(&user1, &user2, &100, &deepest, &0);
+}
+
+#[test]
+fn test_conditional_hash_lock() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.mint(&user1, &1000);
+    e.ledger().set_sequence_number(10);
+
+    let preimage = Bytes::from_slice(&e, b"atomic swap secret");
+    let hash: BytesN<32> = e.crypto().sha256(&preimage).into();
+    let condition = ConditionType::HashLock(hash);
+
+    // Hash kilidi son kullanma tarihi olmadan kabul edilmez
+    assert_eq!(
+        token.try_create_conditional(&user1, &user2, &300, &condition, &0),
+        Err(Ok(ConditionalError::InvalidCondition))
+    );
+
+    let id1 = token.create_conditional(&user1, &user2, &300, &condition, &100);
+    let wrong = Bytes::from_slice(&e, b"wrong secret");
+    assert_eq!(
+        token.try_execute_conditional(&id1, &None, &Some(wrong)),
+        Err(Ok(ConditionalError::ConditionNotMet))
+    );
+    assert_eq!(
+        token.try_execute_conditional(&id1, &None, &None),
+        Err(Ok(ConditionalError::ConditionNotMet))
+    );
+
+    token.execute_conditional(&id1, &None, &Some(preimage.clone()));
+
+    // Ön görüntü yürütme olayında yayınlanır
+    let event = e.events().all().last().unwrap();
+    let data: (i128, u32, Option<Bytes>) = event.2.into_val(&e);
+    assert_eq!(data, (300, id1, Some(preimage.clone())));
+    assert_eq!(token.balance(&user2), 300);
+
+    // Süre dolduktan sonra gönderici iade alır
+    let id2 = token.create_conditional(&user1, &user2, &200, &condition, &100);
+    e.ledger().set_sequence_number(101);
+    assert_eq!(
+        token.try_execute_conditional(&id2, &None, &Some(preimage)),
+        Err(Ok(ConditionalError::TransferExpired))
+    );
+    token.cancel_conditional(&id2, &None);
+    assert_eq!(token.balance(&user1), 700);
+}
+
+// Teslimat onayı veren sahte kahin kontratı
+#[contract]
+struct MockOracle;
+
+#[contractimpl]
+impl MockOracle {
+    pub fn set_delivered(e: Env, order: u32, delivered: bool) {
+        e.storage().instance().set(&order, &delivered);
+    }
+
+    pub fn is_delivered(e: Env, order: u32) -> bool {
+        e.storage().instance().get(&order).unwrap_or(false)
+    }
+
+    pub fn broken(_e: Env) -> bool {
+        panic!("oracle failure")
+    }
+}
+
+#[test]
+fn test_conditional_contract_check() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    let oracle = MockOracleClient::new(&e, &e.register(MockOracle, ()));
+
+    token.mint(&user1, &1000);
+
+    let delivered = ConditionType::ContractCheck(ContractCheckCondition {
+        contract: oracle.address.clone(),
+        fn_name: Symbol::new(&e, "is_delivered"),
+        args: vec![&e, 7_u32.into_val(&e)],
+    });
+    let id1 = token.create_conditional(&user1, &user2, &300, &delivered, &0);
+
+    assert_eq!(
+        token.try_execute_conditional(&id1, &None, &None),
+        Err(Ok(ConditionalError::ConditionNotMet))
+    );
+    oracle.set_delivered(&7, &true);
+    token.execute_conditional(&id1, &None, &None);
+    assert_eq!(token.balance(&user2), 300);
+
+    // Hata veren kahin çağrısı ConditionNotMet olarak döner
+    let broken = ConditionType::ContractCheck(ContractCheckCondition {
+        contract: oracle.address.clone(),
+        fn_name: Symbol::new(&e, "broken"),
+        args: vec![&e],
+    });
+    let id2 = token.create_conditional(&user1, &user2, &100, &broken, &0);
+    assert_eq!(
+        token.try_execute_conditional(&id2, &None, &None),
+        Err(Ok(ConditionalError::ConditionNotMet))
+    );
+}
+
+#[test]
+fn test_milestone_escrow() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let payer = Address::generate(&e);
+    let payee = Address::generate(&e);
+    let reviewer = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.mint(&payer, &1000);
+    e.ledger().set_sequence_number(10);
+
+    let tranches = vec![
+        &e,
+        MilestoneTranche { amount: 100, condition: ConditionType::TimeBasedRelease(20) },
+        MilestoneTranche { amount: 200, condition: ConditionType::ApprovalRequired(reviewer.clone()) },
+        MilestoneTranche { amount: 300, condition: ConditionType::TimeBasedRelease(50) },
+    ];
+    let id = token.create_milestone_escrow(&payer, &payee, &tranches, &100);
+    assert_eq!(token.balance(&payer), 400);
+    assert_eq!(token.balance(&token.address), 600);
+
+    // Kilometre taşları sırayla değil, koşulları sağlandıkça serbest bırakılır
+    token.approve_milestone(&id, &1, &reviewer);
+    token.release_milestone(&id, &1, &None, &None);
+    assert_eq!(token.balance(&payee), 200);
+    assert_eq!(
+        token.try_release_milestone(&id, &1, &None, &None),
+        Err(Ok(MilestoneError::MilestoneAlreadyReleased))
+    );
+    assert_eq!(
+        token.try_release_milestone(&id, &0, &None, &None),
+        Err(Ok(MilestoneError::ConditionNotMet))
+    );
+
+    e.ledger().set_sequence_number(20);
+    token.release_milestone(&id, &0, &None, &None);
+    assert_eq!(token.balance(&payee), 300);
+    assert!(token.get_milestone_escrow(&id).milestones.get(0).unwrap().released);
+
+    // Süre dolmadan iptal için alıcının rızası gerekir
+    assert_eq!(
+        token.try_cancel_milestone_escrow(&id, &None),
+        Err(Ok(MilestoneError::ConsentRequired))
+    );
+    assert_eq!(token.cancel_milestone_escrow(&id, &Some(payee.clone())), 300);
+    assert_eq!(token.balance(&payer), 700);
+    assert_eq!(token.balance(&token.address), 0);
+    assert_eq!(
+        token.try_release_milestone(&id, &2, &None, &None),
+        Err(Ok(MilestoneError::EscrowCancelled))
+    );
+}
+
+#[test]
+fn test_milestone_escrow_expiry_refund() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let payer = Address::generate(&e);
+    let payee = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.mint(&payer, &1000);
+
+    assert_eq!(
+        token.try_create_milestone_escrow(&payer, &payee, &vec![&e], &100),
+        Err(Ok(MilestoneError::InvalidMilestones))
+    );
+    assert_eq!(
+        token.try_create_milestone_escrow(
+            &payer,
+            &payee,
+            &vec![&e, MilestoneTranche { amount: 0, condition: ConditionType::TimeBasedRelease(0) }],
+            &100
+        ),
+        Err(Ok(MilestoneError::InvalidMilestones))
+    );
+    assert_eq!(
+        token.try_create_milestone_escrow(
+            &payer,
+            &payee,
+            &vec![&e, MilestoneTranche { amount: 10, condition: ConditionType::All(vec![&e]) }],
+            &100
+        ),
+        Err(Ok(MilestoneError::InvalidCondition))
+    );
+
+    let tranches = vec![
+        &e,
+        MilestoneTranche { amount: 100, condition: ConditionType::TimeBasedRelease(0) },
+        MilestoneTranche { amount: 200, condition: ConditionType::TimeBasedRelease(500) },
+    ];
+    let id = token.create_milestone_escrow(&payer, &payee, &tranches, &100);
+    token.release_milestone(&id, &0, &None, &None);
+
+    e.ledger().set_sequence_number(101);
+    assert_eq!(
+        token.try_release_milestone(&id, &1, &None, &None),
+        Err(Ok(MilestoneError::EscrowExpired))
+    );
+    assert_eq!(token.cancel_milestone_escrow(&id, &None), 200);
+    assert_eq!(token.balance(&payer), 900);
+    assert_eq!(token.balance(&payee), 100);
+}
+
+#[test]
+fn test_arbiter_escrow_release_and_refund() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let seller = Address::generate(&e);
+    let arbiter = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.mint(&buyer, &1000);
+
+    assert_eq!(
+        token.try_create_escrow(&buyer, &seller, &buyer, &100),
+        Err(Ok(EscrowError::InvalidParties))
+    );
+
+    let id1 = token.create_escrow(&buyer, &seller, &arbiter, &300);
+    assert_eq!(token.balance(&buyer), 700);
+    assert_eq!(token.get_escrow(&id1).status, EscrowStatus::Funded);
+
+    // Anlaşmazlık olmadan hakem paylaştırma yapamaz
+    assert_eq!(
+        token.try_resolve_dispute(&id1, &100),
+        Err(Ok(EscrowError::InvalidState))
+    );
+
+    token.release_escrow(&id1);
+    assert_eq!(token.balance(&seller), 300);
+    assert_eq!(token.get_escrow(&id1).status, EscrowStatus::Released);
+    assert_eq!(token.try_refund_escrow(&id1), Err(Ok(EscrowError::InvalidState)));
+
+    let id2 = token.create_escrow(&buyer, &seller, &arbiter, &200);
+
+    // Dondurulmuş taraflara iade de yapılamaz
+    token.freeze_account(&buyer);
+    assert_eq!(token.try_refund_escrow(&id2), Err(Ok(EscrowError::AccountFrozen)));
+    token.unfreeze_account(&buyer);
+
+    token.refund_escrow(&id2);
+    assert_eq!(token.balance(&buyer), 700);
+    assert_eq!(token.get_escrow(&id2).status, EscrowStatus::Refunded);
+    assert_eq!(token.balance(&token.address), 0);
+}
+
+#[test]
+fn test_arbiter_escrow_dispute_resolution() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let seller = Address::generate(&e);
+    let arbiter = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.mint(&buyer, &1000);
+    let id = token.create_escrow(&buyer, &seller, &arbiter, &400);
+
+    assert_eq!(
+        token.try_dispute_escrow(&id, &arbiter),
+        Err(Ok(EscrowError::NotAParty))
+    );
+    token.dispute_escrow(&id, &seller);
+    assert_eq!(token.get_escrow(&id).status, EscrowStatus::Disputed);
+    assert_eq!(
+        token.try_dispute_escrow(&id, &buyer),
+        Err(Ok(EscrowError::InvalidState))
+    );
+
+    assert_eq!(
+        token.try_resolve_dispute(&id, &401),
+        Err(Ok(EscrowError::InvalidSplit))
+    );
+    token.resolve_dispute(&id, &150);
+    assert_eq!(
+        e.auths(),
+        std::vec![(
+            arbiter.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    token.address.clone(),
+                    Symbol::new(&e, "resolve_dispute"),
+                    (id, 150_i128).into_val(&e),
+                )),
+                sub_invocations: std::vec![]
+            }
+        )]
+    );
+    assert_eq!(token.balance(&seller), 150);
+    assert_eq!(token.balance(&buyer), 850);
+    assert_eq!(token.balance(&token.address), 0);
+    assert_eq!(token.get_escrow(&id).status, EscrowStatus::Resolved);
+    assert_eq!(token.try_release_escrow(&id), Err(Ok(EscrowError::InvalidState)));
+}
+
+#[test]
+fn test_multiple_vesting_schedules() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let beneficiary = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.mint(&admin, &1000);
+
+    // İşe giriş hibesi ve yenileme hibesi
+    let hiring = token.create_vesting(&admin, &beneficiary, &400, &0, &100, &VestingType::Linear, &0, &0, &false, &VestingClock::Ledger);
+    let refresh = token.create_vesting(&admin, &beneficiary, &200, &50, &100, &VestingType::Linear, &0, &0, &false, &VestingClock::Ledger);
+    assert_ne!(hiring, refresh);
+
+    let schedules = token.get_vesting_info(&beneficiary);
+    assert_eq!(schedules.len(), 2);
+    assert_eq!(schedules.get(0).unwrap().id, hiring);
+    assert_eq!(schedules.get(1).unwrap().total_amount, 200);
+
+    e.ledger().set_sequence_number(100);
+    assert_eq!(token.get_vested_amount(&beneficiary, &Some(hiring)), 400);
+    assert_eq!(token.get_vested_amount(&beneficiary, &Some(refresh)), 100);
+    assert_eq!(token.get_vested_amount(&beneficiary, &None), 500);
+
+    // Tek bir planı talep et
+    assert_eq!(token.claim_vesting(&beneficiary, &Some(hiring)), 400);
+    assert_eq!(
+        token.try_claim_vesting(&beneficiary, &Some(hiring)),
+        Err(Ok(VestingError::InsufficientVestedTokens))
+    );
+
+    // Tüm planları talep et
+    e.ledger().set_sequence_number(150);
+    assert_eq!(token.claim_vesting(&beneficiary, &None), 200);
+    assert_eq!(token.balance(&beneficiary), 600);
+    assert_eq!(token.balance(&token.address), 0);
+
+    // Başkasının planı talep edilemez
+    let other = Address::generate(&e);
+    assert_eq!(
+        token.try_claim_vesting(&other, &Some(hiring)),
+        Err(Ok(VestingError::VestingScheduleDoesNotExist))
+    );
+}
+
+#[test]
+fn test_revoke_vesting() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let treasury = Address::generate(&e);
+    let beneficiary = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.mint(&treasury, &1000);
+    let revocable = token.create_vesting(&treasury, &beneficiary, &400, &0, &100, &VestingType::Linear, &0, &0, &true, &VestingClock::Ledger);
+    let fixed = token.create_vesting(&treasury, &beneficiary, &200, &0, &100, &VestingType::Linear, &0, &0, &false, &VestingClock::Ledger);
+    assert_eq!(token.balance(&treasury), 400);
+
+    e.ledger().set_sequence_number(25);
+    assert_eq!(
+        token.try_revoke_vesting(&beneficiary, &Some(fixed)),
+        Err(Ok(VestingError::VestingNotRevocable))
+    );
+
+    // Hak edilmemiş 300 token hazineye döner
+    assert_eq!(token.revoke_vesting(&beneficiary, &None), 300);
+    assert_eq!(token.balance(&treasury), 700);
+    assert_eq!(
+        token.try_revoke_vesting(&beneficiary, &Some(revocable)),
+        Err(Ok(VestingError::VestingAlreadyRevoked))
+    );
+    assert_eq!(
+        token.try_revoke_vesting(&beneficiary, &None),
+        Err(Ok(VestingError::VestingNotRevocable))
+    );
+
+    // İptal anında hak edilen miktar sabit kalır ve talep edilebilir
+    e.ledger().set_sequence_number(100);
+    assert_eq!(token.get_vested_amount(&beneficiary, &Some(revocable)), 100);
+    assert_eq!(token.claim_vesting(&beneficiary, &None), 300);
+    assert_eq!(token.balance(&token.address), 0);
+}
+
+// İlk sürümün instance depolamadaki plan anahtarı
+#[contracttype]
+enum BaselineDataKey {
+    VestingSchedule(Address),
+}
+
+// İlk sürümün plan düzeni
+#[contracttype]
+struct BaselineVestingSchedule {
+    beneficiary: Address,
+    total_amount: i128,
+    start_ledger: u32,
+    duration_ledgers: u32,
+    vesting_type: VestingType,
+    claimed_amount: i128,
+    steps: u32,
+    cliff_ledger: u32,
+}
+
+#[test]
+fn test_migrate_vesting_storage() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let beneficiary = Address::generate(&e);
+    let other = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    // İlk sürümün yazdığı, 100'ü talep edilmiş planı ve emanetini taklit et
+    token.mint(&token.address, &400);
+    let baseline = BaselineVestingSchedule {
+        beneficiary: beneficiary.clone(),
+        total_amount: 500,
+        start_ledger: 0,
+        duration_ledgers: 100,
+        vesting_type: VestingType::Linear,
+        claimed_amount: 100,
+        steps: 0,
+        cliff_ledger: 0,
+    };
+    e.as_contract(&token.address, || {
+        e.storage()
+            .instance()
+            .set(&BaselineDataKey::VestingSchedule(beneficiary.clone()), &baseline);
+    });
+
+    assert_eq!(
+        token.try_get_vesting_info(&beneficiary).err(),
+        Some(Ok(VestingError::VestingScheduleDoesNotExist))
+    );
+
+    // Eski planı olmayan hak sahipleri atlanır
+    assert_eq!(token.migrate_vesting(&vec![&e, other.clone(), beneficiary.clone()]), 1);
+    e.as_contract(&token.address, || {
+        assert!(!e
+            .storage()
+            .instance()
+            .has(&BaselineDataKey::VestingSchedule(beneficiary.clone())));
+        assert!(e.storage().persistent().has(&DataKey::VestingSchedule(1)));
+    });
+    let schedule = token.get_vesting_info(&beneficiary).get(0).unwrap();
+    assert_eq!(schedule.id, 1);
+    assert_eq!(schedule.funder, admin);
+    assert_eq!(schedule.claimed_amount, 100);
+    assert!(!schedule.revocable);
+    assert_eq!(
+        token.try_migrate_vesting(&vec![&e, beneficiary.clone()]),
+        Err(Ok(VestingError::MigrationAlreadyDone))
+    );
+
+    // Taşınan planlara yeni planlar eklenebilir
+    token.mint(&admin, &100);
+    let id = token.create_vesting(&admin, &beneficiary, &100, &0, &100, &VestingType::Linear, &0, &0, &false, &VestingClock::Ledger);
+    assert_eq!(id, 2);
+
+    e.ledger().set_sequence_number(100);
+    assert_eq!(token.claim_vesting(&beneficiary, &None), 500);
+    assert_eq!(token.balance(&token.address), 0);
+}
+
+#[test]
+fn test_linear_vesting_with_cliff() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let beneficiary = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.mint(&admin, &10000);
+
+    // Cliff hakediş penceresinin dışında olamaz
+    for cliff in [99_u32, 501] {
+        assert_eq!(
+            token.try_create_vesting(&admin, &beneficiary, &4800, &100, &400, &VestingType::LinearWithCliff, &0, &cliff, &false, &VestingClock::Ledger),
+            Err(Ok(VestingError::InvalidCliff))
+        );
+    }
+
+    // 400 ledger'lık doğrusal hakediş, 100 ledger'lık cliff
+    let id = token.create_vesting(&admin, &beneficiary, &4800, &100, &400, &VestingType::LinearWithCliff, &0, &200, &false, &VestingClock::Ledger);
+
+    e.ledger().set_sequence_number(199);
+    assert_eq!(token.get_vested_amount(&beneficiary, &Some(id)), 0);
+
+    e.ledger().set_sequence_number(200);
+    assert_eq!(token.get_vested_amount(&beneficiary, &Some(id)), 1200);
+
+    e.ledger().set_sequence_number(300);
+    assert_eq!(token.get_vested_amount(&beneficiary, &Some(id)), 2400);
+
+    e.ledger().set_sequence_number(500);
+    assert_eq!(token.claim_vesting(&beneficiary, &Some(id)), 4800);
+}
+
+#[test]
+fn test_custom_vesting_schedule() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let beneficiary = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.mint(&admin, &10000);
+
+    let point = |ledger: u32, cumulative_amount: i128| UnlockPoint { ledger, cumulative_amount };
+
+    // Sıralı olmayan noktalar reddedilir
+    let unordered = VestingType::Custom(vec![&e, point(200, 100), point(150, 1000)]);
+    assert_eq!(
+        token.try_create_vesting(&admin, &beneficiary, &1000, &100, &400, &unordered, &0, &0, &false, &VestingClock::Ledger),
+        Err(Ok(VestingError::InvalidUnlockPoints))
+    );
+
+    // Son nokta toplam miktara eşit olmalı
+    let short = VestingType::Custom(vec![&e, point(100, 100), point(500, 900)]);
+    assert_eq!(
+        token.try_create_vesting(&admin, &beneficiary, &1000, &100, &400, &short, &0, &0, &false, &VestingClock::Ledger),
+        Err(Ok(VestingError::UnlockPointsMismatch))
+    );
+
+    // Başlangıçta %10, sonra düzensiz açılışlar
+    let custom = VestingType::Custom(vec![
+        &e,
+        point(100, 100),
+        point(200, 250),
+        point(300, 400),
+        point(500, 1000),
+    ]);
+    let id = token.create_vesting(&admin, &beneficiary, &1000, &100, &400, &custom, &0, &0, &false, &VestingClock::Ledger);
+
+    e.ledger().set_sequence_number(99);
+    assert_eq!(token.get_vested_amount(&beneficiary, &Some(id)), 0);
+
+    e.ledger().set_sequence_number(100);
+    assert_eq!(token.get_vested_amount(&beneficiary, &Some(id)), 100);
+
+    e.ledger().set_sequence_number(299);
+    assert_eq!(token.claim_vesting(&beneficiary, &Some(id)), 250);
+
+    e.ledger().set_sequence_number(499);
+    assert_eq!(token.get_vested_amount(&beneficiary, &Some(id)), 400);
+
+    e.ledger().set_sequence_number(500);
+    assert_eq!(token.claim_vesting(&beneficiary, &Some(id)), 750);
+    assert_eq!(token.balance(&beneficiary), 1000);
+}
+
+#[test]
+fn test_transfer_vesting() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let old_wallet = Address::generate(&e);
+    let new_wallet = Address::generate(&e);
+    let other = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.mint(&admin, &10000);
+    let id = token.create_vesting(&admin, &old_wallet, &1000, &0, &100, &VestingType::Linear, &0, &0, &false, &VestingClock::Ledger);
+    token.create_vesting(&admin, &other, &500, &0, &100, &VestingType::Linear, &0, &0, &false, &VestingClock::Ledger);
+
+    e.ledger().set_sequence_number(40);
+    assert_eq!(token.claim_vesting(&old_wallet, &None), 400);
+
+    // Planı olan bir hak sahibinin üzerine taşınamaz
+    assert_eq!(
+        token.try_transfer_vesting(&old_wallet, &other),
+        Err(Ok(VestingError::BeneficiaryHasSchedule))
+    );
+
+    // Varsayılan olarak sadece eski hak sahibinin onayı yeterli
+    assert_eq!(token.transfer_vesting(&old_wallet, &new_wallet), 1);
+    let signers: std::vec::Vec<Address> = e.auths().into_iter().map(|(addr, _)| addr).collect();
+    assert_eq!(signers, std::vec![old_wallet.clone()]);
+
+    // Talep edilen miktar planla birlikte taşınır
+    let schedules = token.get_vesting_info(&new_wallet);
+    assert_eq!(schedules.get(0).unwrap().id, id);
+    assert_eq!(schedules.get(0).unwrap().claimed_amount, 400);
+    assert_eq!(
+        token.try_get_vesting_info(&old_wallet).err(),
+        Some(Ok(VestingError::VestingScheduleDoesNotExist))
+    );
+
+    e.ledger().set_sequence_number(100);
+    assert_eq!(token.claim_vesting(&new_wallet, &None), 600);
+    assert_eq!(token.balance(&old_wallet), 400);
+    assert_eq!(token.balance(&new_wallet), 600);
+
+    // Yönetici onayı zorunlu hale getirildiğinde her iki imza da gerekir
+    token.set_vesting_transfer_policy(&true);
+    token.transfer_vesting(&other, &old_wallet);
+    let signers: std::vec::Vec<Address> = e.auths().into_iter().map(|(addr, _)| addr).collect();
+    assert_eq!(signers, std::vec![other.clone(), admin.clone()]);
+    assert_eq!(token.get_vested_amount(&old_wallet, &None), 500);
+}
+
+#[test]
+fn test_claim_vesting_to_destination() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let beneficiary = Address::generate(&e);
+    let cold_wallet = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.mint(&admin, &10000);
+    token.create_vesting(&admin, &beneficiary, &1000, &0, &100, &VestingType::Linear, &0, &0, &false, &VestingClock::Ledger);
+    token.create_vesting(&admin, &beneficiary, &500, &0, &100, &VestingType::Cliff, &0, &50, &false, &VestingClock::Ledger);
+
+    // Dondurulmuş bir hesaba gönderilemez
+    token.freeze_account(&cold_wallet);
+    e.ledger().set_sequence_number(60);
+    assert_eq!(
+        token.try_claim_vesting_to(&beneficiary, &cold_wallet),
+        Err(Ok(VestingError::AccountFrozen))
+    );
+    token.unfreeze_account(&cold_wallet);
+
+    // Dondurulmuş hak sahibi de başka bir adrese talep edemez
+    token.freeze_account(&beneficiary);
+    assert_eq!(
+        token.try_claim_vesting_to(&beneficiary, &cold_wallet),
+        Err(Ok(VestingError::AccountFrozen))
+    );
+    token.unfreeze_account(&beneficiary);
+
+    // Talep hak sahibinin onayını gerektirir, tokenler hedef adrese gider
+    assert_eq!(token.claim_vesting_to(&beneficiary, &cold_wallet), 1100);
+    let signers: std::vec::Vec<Address> = e.auths().into_iter().map(|(addr, _)| addr).collect();
+    assert_eq!(signers, std::vec![beneficiary.clone()]);
+    assert_eq!(token.balance(&cold_wallet), 1100);
+    assert_eq!(token.balance(&beneficiary), 0);
+
+    assert_eq!(
+        token.try_claim_vesting_to(&beneficiary, &cold_wallet),
+        Err(Ok(VestingError::InsufficientVestedTokens))
+    );
+}
+
+#[test]
+fn test_release_vested_batch() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let keeper = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let carol = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.mint(&admin, &10000);
+    token.create_vesting(&admin, &alice, &1000, &0, &100, &VestingType::Linear, &0, &0, &false, &VestingClock::Ledger);
+    token.create_vesting(&admin, &bob, &2000, &0, &100, &VestingType::Linear, &0, &0, &false, &VestingClock::Ledger);
+    token.create_vesting(&admin, &carol, &500, &0, &100, &VestingType::Cliff, &0, &80, &false, &VestingClock::Ledger);
+
+    assert_eq!(
+        token.try_release_vested(&vec![&e]),
+        Err(Ok(VestingError::InvalidBeneficiaries))
+    );
+
+    // Hiçbir hak sahibinin imzası gerekmez; planı ya da hakedişi olmayanlar atlanır
+    e.ledger().set_sequence_number(50);
+    assert_eq!(token.release_vested(&vec![&e, alice.clone(), bob.clone(), carol.clone(), keeper.clone()]), 1500);
+    assert!(e.auths().is_empty());
+    assert_eq!(token.balance(&alice), 500);
+    assert_eq!(token.balance(&bob), 1000);
+    assert_eq!(token.balance(&carol), 0);
+
+    e.ledger().set_sequence_number(100);
+    assert_eq!(token.release_vested(&vec![&e, alice.clone(), bob.clone(), carol.clone()]), 2000);
+    assert_eq!(token.balance(&alice), 1000);
+    assert_eq!(token.balance(&bob), 2000);
+    assert_eq!(token.balance(&carol), 500);
+    assert_eq!(token.balance(&token.address), 0);
+}
+
+#[test]
+fn test_vesting_parameter_validation() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let beneficiary = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.mint(&admin, &10000);
+
+    let cases = [
+        (0_i128, 0_u32, 100_u32, VestingType::Linear, 0_u32, 0_u32, VestingError::InvalidAmount),
+        (1000, 0, 0, VestingType::Linear, 0, 0, VestingError::InvalidDuration),
+        (1000, u32::MAX - 10, 100, VestingType::Linear, 0, 0, VestingError::ScheduleOverflow),
+        (1000, 0, 100, VestingType::Stepped, 0, 0, VestingError::InvalidSteps),
+        (1000, 0, 100, VestingType::Stepped, 101, 0, VestingError::StepsExceedDuration),
+        (1000, 100, 100, VestingType::Cliff, 0, 50, VestingError::InvalidCliff),
+        (1000, 100, 100, VestingType::Cliff, 0, 201, VestingError::InvalidCliff),
+    ];
+    for (total, start, duration, vesting_type, steps, cliff, error) in cases {
+        assert_eq!(
+            token.try_create_vesting(&admin, &beneficiary, &total, &start, &duration, &vesting_type, &steps, &cliff, &false, &VestingClock::Ledger),
+            Err(Ok(error))
+        );
+    }
+
+    // Hatalı planlar hiçbir tokeni kilitlemez
+    assert_eq!(token.balance(&admin), 10000);
+    assert_eq!(token.balance(&token.address), 0);
+
+    // Her adımın bir ledger sürdüğü sınır durumu kabul edilir
+    let id = token.create_vesting(&admin, &beneficiary, &1000, &0, &100, &VestingType::Stepped, &100, &0, &false, &VestingClock::Ledger);
+    e.ledger().set_sequence_number(37);
+    assert_eq!(token.get_vested_amount(&beneficiary, &Some(id)), 370);
+}
+
+#[test]
+fn test_timestamp_vesting_clock() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let beneficiary = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.mint(&admin, &10000);
+
+    // Sözleşme tarihleri Unix saniyesi olarak: 1 Ocak 2026'dan itibaren 4 çeyrek
+    let start: u32 = 1_767_225_600;
+    let quarter: u32 = 90 * 24 * 60 * 60;
+    let stepped = token.create_vesting(
+        &admin, &beneficiary, &4000, &start, &(4 * quarter), &VestingType::Stepped, &4, &0, &false, &VestingClock::Timestamp
+    );
+    let cliff = token.create_vesting(
+        &admin, &beneficiary, &1000, &start, &(4 * quarter), &VestingType::Cliff, &0, &(start + quarter), &false, &VestingClock::Timestamp
+    );
+
+    // Ledger sıra numarası ilerlese de zaman damgası gelmeden hiçbir şey açılmaz
+    e.ledger().set_sequence_number(100_000);
+    e.ledger().set_timestamp(start as u64 + quarter as u64 - 1);
+    assert_eq!(token.get_vested_amount(&beneficiary, &Some(stepped)), 0);
+    assert_eq!(token.get_vested_amount(&beneficiary, &Some(cliff)), 0);
+
+    e.ledger().set_timestamp(start as u64 + quarter as u64);
+    assert_eq!(token.get_vested_amount(&beneficiary, &Some(stepped)), 1000);
+    assert_eq!(token.get_vested_amount(&beneficiary, &Some(cliff)), 1000);
+
+    e.ledger().set_timestamp(start as u64 + 3 * quarter as u64 + 1);
+    assert_eq!(token.claim_vesting(&beneficiary, &None), 4000);
+
+    e.ledger().set_timestamp(start as u64 + 4 * quarter as u64);
+    assert_eq!(token.claim_vesting(&beneficiary, &None), 1000);
+}
+
+#[test]
+fn test_pause_and_resume_vesting() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let beneficiary = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.mint(&admin, &10000);
+    let linear = token.create_vesting(&admin, &beneficiary, &1000, &0, &100, &VestingType::Linear, &0, &0, &false, &VestingClock::Ledger);
+    let cliff = token.create_vesting(&admin, &beneficiary, &500, &0, &100, &VestingType::Cliff, &0, &60, &false, &VestingClock::Ledger);
+
+    assert_eq!(
+        token.try_resume_vesting(&beneficiary, &Some(linear)),
+        Err(Ok(VestingError::VestingNotPaused))
+    );
+
+    // İzin başlangıcı: birikim durur
+    e.ledger().set_sequence_number(40);
+    assert_eq!(token.pause_vesting(&beneficiary, &None), 2);
+    assert_eq!(
+        token.try_pause_vesting(&beneficiary, &Some(cliff)),
+        Err(Ok(VestingError::VestingAlreadyPaused))
+    );
+
+    e.ledger().set_sequence_number(90);
+    assert_eq!(token.get_vested_amount(&beneficiary, &Some(linear)), 400);
+    assert_eq!(token.get_vested_amount(&beneficiary, &Some(cliff)), 0);
+    assert_eq!(token.claim_vesting(&beneficiary, &None), 400);
+
+    // 50 ledger'lık izin bitiş ve cliff zamanlarını ileri iter
+    assert_eq!(token.resume_vesting(&beneficiary, &None), 2);
+    let schedule = token.get_vesting_info(&beneficiary).get(0).unwrap();
+    assert_eq!(schedule.paused_intervals.len(), 1);
+    assert!(schedule.paused_at.is_none());
+
+    e.ledger().set_sequence_number(109);
+    assert_eq!(token.get_vested_amount(&beneficiary, &Some(linear)), 590);
+    assert_eq!(token.get_vested_amount(&beneficiary, &Some(cliff)), 0);
+
+    e.ledger().set_sequence_number(110);
+    assert_eq!(token.get_vested_amount(&beneficiary, &Some(cliff)), 500);
+
+    e.ledger().set_sequence_number(149);
+    assert_eq!(token.get_vested_amount(&beneficiary, &Some(linear)), 990);
+
+    e.ledger().set_sequence_number(150);
+    assert_eq!(token.claim_vesting(&beneficiary, &None), 1100);
+    assert_eq!(token.balance(&beneficiary), 1500);
+}
+
+#[test]
+fn test_vesting_timeline() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let beneficiary = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.mint(&admin, &10000);
+    let stepped = token.create_vesting(&admin, &beneficiary, &1000, &100, &400, &VestingType::Stepped, &4, &0, &false, &VestingClock::Ledger);
+    let cliff = token.create_vesting(&admin, &beneficiary, &500, &100, &400, &VestingType::Cliff, &0, &250, &false, &VestingClock::Ledger);
+    let linear = token.create_vesting(&admin, &beneficiary, &800, &100, &400, &VestingType::Linear, &0, &0, &true, &VestingClock::Ledger);
+
+    assert_eq!(
+        token.try_vesting_timeline(&beneficiary, &500, &100, &5, &VestingClock::Ledger).err(),
+        Some(Ok(VestingError::InvalidTimelineRange))
+    );
+    assert_eq!(
+        token.try_vesting_timeline(&beneficiary, &100, &500, &0, &VestingClock::Ledger).err(),
+        Some(Ok(VestingError::InvalidTimelineRange))
+    );
+
+    e.ledger().set_sequence_number(210);
+    let timelines = token.vesting_timeline(&beneficiary, &100, &500, &5, &VestingClock::Ledger);
+    assert_eq!(timelines.len(), 3);
+
+    let vested_at = |timeline: &VestingTimeline| -> std::vec::Vec<(u64, i128)> {
+        timeline.samples.iter().map(|p| (p.at, p.vested)).collect()
+    };
+
+    let timeline = timelines.get(0).unwrap();
+    assert_eq!(timeline.schedule_id, stepped);
+    assert_eq!(vested_at(&timeline), std::vec![(100, 0), (200, 250), (300, 500), (400, 750), (500, 1000)]);
+    assert_eq!(timeline.next_unlock, Some(300));
+
+    let timeline = timelines.get(1).unwrap();
+    assert_eq!(timeline.schedule_id, cliff);
+    assert_eq!(vested_at(&timeline), std::vec![(100, 0), (200, 0), (300, 500), (400, 500), (500, 500)]);
+    assert_eq!(timeline.next_unlock, Some(250));
+
+    let timeline = timelines.get(2).unwrap();
+    assert_eq!(timeline.schedule_id, linear);
+    assert_eq!(vested_at(&timeline), std::vec![(100, 0), (200, 200), (300, 400), (400, 600), (500, 800)]);
+    assert_eq!(timeline.next_unlock, None);
+
+    // Zaman damgalı planlar sadece kendi saat kaynağıyla örneklenir
+    let start: u32 = 1_767_225_600;
+    let timestamped = token.create_vesting(&admin, &beneficiary, &1000, &start, &400, &VestingType::Linear, &0, &0, &false, &VestingClock::Timestamp);
+    assert_eq!(token.vesting_timeline(&beneficiary, &100, &500, &5, &VestingClock::Ledger).len(), 3);
+    let timelines = token.vesting_timeline(&beneficiary, &(start as u64), &(start as u64 + 400), &5, &VestingClock::Timestamp);
+    assert_eq!(timelines.len(), 1);
+    let timeline = timelines.get(0).unwrap();
+    assert_eq!(timeline.schedule_id, timestamped);
+    assert!(timeline.clock == VestingClock::Timestamp);
+    assert_eq!(
+        timeline.samples.iter().map(|p| p.vested).collect::<std::vec::Vec<i128>>(),
+        std::vec![0, 250, 500, 750, 1000]
+    );
+
+    // Hak sahibinin o saatte planı yoksa aralık geçersizdir
+    let other = Address::generate(&e);
+    token.create_vesting(&admin, &other, &100, &0, &100, &VestingType::Linear, &0, &0, &false, &VestingClock::Ledger);
+    assert_eq!(
+        token.try_vesting_timeline(&other, &0, &100, &2, &VestingClock::Timestamp).err(),
+        Some(Ok(VestingError::InvalidTimelineRange))
+    );
+
+    // Duraklatılan süre bir sonraki açılışı ileri iter
+    token.pause_vesting(&beneficiary, &Some(cliff));
+    e.ledger().set_sequence_number(240);
+    assert_eq!(token.vesting_timeline(&beneficiary, &240, &240, &1, &VestingClock::Ledger).get(1).unwrap().next_unlock, None);
+    token.resume_vesting(&beneficiary, &Some(cliff));
+    assert_eq!(token.vesting_timeline(&beneficiary, &240, &240, &1, &VestingClock::Ledger).get(1).unwrap().next_unlock, Some(280));
+
+    // Hızlandırma ve iptal sadece gerçekleştikleri zamandan sonraki örnekleri etkiler
+    token.accelerate_vesting(&beneficiary, &5_000);
+    let timeline = token.vesting_timeline(&beneficiary, &100, &500, &5, &VestingClock::Ledger).get(2).unwrap();
+    assert_eq!(vested_at(&timeline), std::vec![(100, 0), (200, 200), (300, 600), (400, 700), (500, 800)]);
+
+    token.revoke_vesting(&beneficiary, &Some(linear));
+    let timeline = token.vesting_timeline(&beneficiary, &100, &500, &5, &VestingClock::Ledger).get(2).unwrap();
+    assert_eq!(vested_at(&timeline), std::vec![(100, 0), (200, 200), (300, 540), (400, 540), (500, 540)]);
+}
+
+#[test]
+fn test_accelerate_vesting() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.mint(&admin, &10000);
+    let alice_id = token.create_vesting(&admin, &alice, &1000, &0, &100, &VestingType::Linear, &0, &0, &true, &VestingClock::Ledger);
+    token.create_vesting(&admin, &bob, &2000, &0, &100, &VestingType::Cliff, &0, &80, &true, &VestingClock::Ledger);
+
+    assert_eq!(
+        token.try_accelerate_vesting(&alice, &10_001),
+        Err(Ok(VestingError::InvalidAccelerationBps))
+    );
+
+    // Kalan 800'ün %50'si hemen serbest kalır
+    e.ledger().set_sequence_number(20);
+    assert_eq!(token.accelerate_vesting(&alice, &5_000), 400);
+    let event = e.events().all().last().unwrap();
+    let data: (i128, u32, u32) = event.2.into_val(&e);
+    assert_eq!(data, (400, 5_000, alice_id));
+    assert_eq!(token.get_vested_amount(&alice, &None), 600);
+    assert_eq!(token.get_vesting_info(&alice).get(0).unwrap().accelerated_amount, 500);
+
+    // Kalan 400 takvimin geri kalanında serbest kalır, plan erken tamamlanmaz
+    e.ledger().set_sequence_number(70);
+    assert_eq!(token.get_vested_amount(&alice, &None), 850);
+
+    // İptalde takvimde kalan kısım fonlayana döner
+    assert_eq!(token.revoke_vesting(&alice, &None), 150);
+    assert_eq!(token.balance(&admin), 7150);
+
+    // Şirket çapında hızlandırma sayfa sayfa işlenir; iptal edilen planlar atlanır
+    assert_eq!(token.accelerate_all(&10_000, &1, &1), 0);
+    assert_eq!(token.accelerate_all(&10_000, &2, &50), 2000);
+    assert_eq!(token.get_vested_amount(&bob, &None), 2000);
+    assert_eq!(token.get_vested_amount(&alice, &None), 850);
+    assert_eq!(token.claim_vesting(&bob, &None), 2000);
+
+    // Performans planında iade edilen dilimin sadece takvimde kalan payı döner
+    let carol = Address::generate(&e);
+    let attestor = Address::generate(&e);
+    let tranche = |amount: i128, deadline: Option<u64>| PerformanceTranche {
+        amount,
+        deadline,
+        attested: false,
+        forfeited: false,
+    };
+    let performance = VestingType::Performance(PerformanceCondition {
+        attestor,
+        tranches: vec![&e, tranche(600, None), tranche(400, Some(80))],
+    });
+    token.create_vesting(&admin, &carol, &1000, &0, &100, &performance, &0, &0, &false, &VestingClock::Ledger);
+    assert_eq!(token.accelerate_vesting(&carol, &5_000), 500);
+    assert_eq!(token.attest_milestone(&carol, &0, &None), 600);
+    assert_eq!(token.get_vested_amount(&carol, &None), 800);
+
+    e.ledger().set_sequence_number(81);
+    assert_eq!(token.forfeit_tranche(&carol, &1, &None), 200);
+    assert_eq!(token.get_vested_amount(&carol, &None), 800);
+    assert_eq!(token.claim_vesting(&carol, &None), 800);
+
+    // Bir plandaki hızlandırma kayıtları sınırlıdır
+    let dave = Address::generate(&e);
+    token.mint(&admin, &1_000_000);
+    let dave_id = token.create_vesting(&admin, &dave, &1_000_000, &0, &1000, &VestingType::Linear, &0, &0, &false, &VestingClock::Ledger);
+    for _ in 0..MAX_ACCELERATIONS {
+        assert!(token.accelerate_vesting(&dave, &1) > 0);
+    }
+    assert_eq!(
+        token.try_accelerate_vesting(&dave, &1),
+        Err(Ok(VestingError::AccelerationLimitReached))
+    );
+
+    // Şirket çapında hızlandırma sınıra ulaşmış planı atlar
+    let vested = token.get_vested_amount(&dave, &None);
+    assert_eq!(token.accelerate_all(&5_000, &dave_id, &1), 0);
+    assert_eq!(token.get_vested_amount(&dave, &None), vested);
+}
+
+#[test]
+fn test_vesting_large_amounts() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    // 18 ondalıklı bir token için bin token
+    let unit: i128 = 1_000_000_000_000_000_000;
+    token.mint(&admin, &(10_000 * unit));
+
+    token.create_vesting(&admin, &alice, &(1000 * unit), &0, &100, &VestingType::Linear, &0, &0, &true, &VestingClock::Ledger);
+    let point = |ledger: u32, cumulative_amount: i128| UnlockPoint { ledger, cumulative_amount };
+    let custom = VestingType::Custom(vec![&e, point(50, 400 * unit), point(100, 1000 * unit)]);
+    token.create_vesting(&admin, &bob, &(1000 * unit), &0, &100, &custom, &0, &0, &true, &VestingClock::Ledger);
+
+    e.ledger().set_sequence_number(50);
+    assert_eq!(token.get_vested_amount(&alice, &None), 500 * unit);
+    assert_eq!(token.get_vested_amount(&bob, &None), 400 * unit);
+
+    // Hızlandırmadan sonra kalan kısım büyük miktarlarda da taşmadan orantılanır
+    assert_eq!(token.accelerate_vesting(&alice, &5_000), 250 * unit);
+    assert_eq!(token.accelerate_vesting(&bob, &5_000), 300 * unit);
+    assert_eq!(token.get_vested_amount(&bob, &None), 700 * unit);
+
+    e.ledger().set_sequence_number(75);
+    assert_eq!(token.get_vested_amount(&alice, &None), 875 * unit);
+    e.ledger().set_sequence_number(100);
+    assert_eq!(token.claim_vesting(&alice, &None), 1000 * unit);
+    assert_eq!(token.claim_vesting(&bob, &None), 1000 * unit);
+
+    // Büyük dilimlerde iade de taşmadan hesaplanır
+    let carol = Address::generate(&e);
+    let attestor = Address::generate(&e);
+    let tranche = |amount: i128, deadline: Option<u64>| PerformanceTranche {
+        amount,
+        deadline,
+        attested: false,
+        forfeited: false,
+    };
+    let performance = VestingType::Performance(PerformanceCondition {
+        attestor,
+        tranches: vec![&e, tranche(600 * unit, None), tranche(400 * unit, Some(150))],
+    });
+    token.create_vesting(&admin, &carol, &(1000 * unit), &0, &100, &performance, &0, &0, &false, &VestingClock::Ledger);
+    assert_eq!(token.accelerate_vesting(&carol, &5_000), 500 * unit);
+    assert_eq!(token.attest_milestone(&carol, &0, &None), 600 * unit);
+    assert_eq!(token.get_vested_amount(&carol, &None), 800 * unit);
+
+    e.ledger().set_sequence_number(151);
+    let balance = token.balance(&admin);
+    assert_eq!(token.forfeit_tranche(&carol, &1, &None), 200 * unit);
+    assert_eq!(token.balance(&admin), balance + 200 * unit);
+    assert_eq!(token.claim_vesting(&carol, &None), 800 * unit);
+}
+
+#[test]
+fn test_performance_vesting_tranches() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let treasury = Address::generate(&e);
+    let advisor = Address::generate(&e);
+    let attestor = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.mint(&treasury, &10000);
+
+    let tranche = |amount: i128, deadline: Option<u64>| PerformanceTranche {
+        amount,
+        deadline,
+        attested: false,
+        forfeited: false,
+    };
+    let performance = VestingType::Performance(PerformanceCondition {
+        attestor: attestor.clone(),
+        tranches: vec![&e, tranche(600, None), tranche(400, Some(200))],
+    });
+
+    // Dilimler toplam miktara eşit olmalı
+    assert_eq!(
+        token.try_create_vesting(&treasury, &advisor, &1200, &0, &1000, &performance, &0, &0, &false, &VestingClock::Ledger),
+        Err(Ok(VestingError::InvalidTranches))
+    );
+
+    let id = token.create_vesting(&treasury, &advisor, &1000, &0, &1000, &performance, &0, &0, &false, &VestingClock::Ledger);
+
+    // Süre dolsa da onay olmadan hiçbir şey serbest kalmaz
+    e.ledger().set_sequence_number(150);
+    assert_eq!(token.get_vested_amount(&advisor, &Some(id)), 0);
+    assert_eq!(
+        token.try_forfeit_tranche(&advisor, &1, &None),
+        Err(Ok(VestingError::DeadlineNotReached))
+    );
+
+    // Dilim sadece onaylayıcının onayıyla açılır
+    assert_eq!(token.attest_milestone(&advisor, &0, &None), 600);
+    let signers: std::vec::Vec<Address> = e.auths().into_iter().map(|(addr, _)| addr).collect();
+    assert_eq!(signers, std::vec![attestor.clone()]);
+    assert_eq!(
+        token.try_attest_milestone(&advisor, &0, &None),
+        Err(Ok(VestingError::TrancheAlreadyAttested))
+    );
+    assert_eq!(
+        token.try_attest_milestone(&advisor, &2, &None),
+        Err(Ok(VestingError::TrancheNotFound))
+    );
+    assert_eq!(token.claim_vesting(&advisor, &None), 600);
+
+    // Son tarihten sonra onay verilemez, dilim fonlayana iade edilir
+    e.ledger().set_sequence_number(201);
+    assert_eq!(
+        token.try_attest_milestone(&advisor, &1, &None),
+        Err(Ok(VestingError::TrancheExpired))
+    );
+    assert_eq!(token.forfeit_tranche(&advisor, &1, &None), 400);
+    assert_eq!(
+        token.try_forfeit_tranche(&advisor, &1, &None),
+        Err(Ok(VestingError::TrancheForfeited))
+    );
+    assert_eq!(token.balance(&treasury), 9400);
+    assert_eq!(token.balance(&token.address), 0);
+    assert_eq!(token.get_vesting_info(&advisor).get(0).unwrap().total_amount, 600);
+
+    // Birden fazla performans planı varsa plan belirtilmelidir
+    let second = token.create_vesting(&treasury, &advisor, &500, &0, &1000, &VestingType::Performance(PerformanceCondition {
+        attestor: attestor.clone(),
+        tranches: vec![&e, tranche(300, None), tranche(200, Some(300))],
+    }), &0, &0, &false, &VestingClock::Ledger);
+    assert_eq!(
+        token.try_attest_milestone(&advisor, &0, &None),
+        Err(Ok(VestingError::AmbiguousSchedule))
+    );
+    assert_eq!(token.attest_milestone(&advisor, &0, &Some(second)), 300);
+    assert_eq!(token.get_vested_amount(&advisor, &Some(second)), 300);
+
+    e.ledger().set_sequence_number(301);
+    assert_eq!(token.forfeit_tranche(&advisor, &1, &Some(second)), 200);
+    assert_eq!(token.balance(&treasury), 9100);
+}
@@ -1,5 +1,10 @@
-use soroban_sdk::{Address, Env, contracterror, contracttype};
-use crate::storage_types::{DataKey, INSTANCE_BUMP_AMOUNT, INSTANCE_LIFETIME_THRESHOLD};
+use soroban_sdk::{Address, Env, I256, Vec, contracterror, contracttype};
+use crate::balance::{receive_balance, spend_balance};
+use crate::storage_types::{
+    DataKey, BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT,
+    INSTANCE_LIFETIME_THRESHOLD,
+};
+use soroban_token_sdk::TokenUtils;
 
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -9,53 +14,282 @@ pub enum VestingError {
     VestingScheduleDoesNotExist = 2,
     InsufficientVestedTokens = 3,
     InvalidVestingParameters = 4,
+    AccountFrozen = 5,
+    VestingNotRevocable = 6,
+    VestingAlreadyRevoked = 7,
+    MigrationAlreadyDone = 8,
+    InvalidCliff = 9,
+    InvalidUnlockPoints = 10,
+    UnlockPointsMismatch = 11,
+    BeneficiaryHasSchedule = 12,
+    InvalidBeneficiaries = 13,
+    InvalidAmount = 14,
+    InvalidDuration = 15,
+    InvalidSteps = 16,
+    StepsExceedDuration = 17,
+    ScheduleOverflow = 18,
+    VestingAlreadyPaused = 19,
+    VestingNotPaused = 20,
+    PauseLimitReached = 21,
+    InvalidTimelineRange = 22,
+    InvalidAccelerationBps = 23,
+    InvalidTranches = 24,
+    TrancheNotFound = 25,
+    TrancheAlreadyAttested = 26,
+    TrancheForfeited = 27,
+    TrancheExpired = 28,
+    DeadlineNotReached = 29,
+    AmbiguousSchedule = 30,
+    AccelerationLimitReached = 31,
 }
 
+// Tek bir toplu çağrıda işlenebilecek en fazla hak sahibi ya da plan
+pub const MAX_RELEASE_BATCH: u32 = 50;
+
+// Bir planda kaydedilebilecek en fazla duraklatma aralığı
+pub const MAX_PAUSE_INTERVALS: u32 = 20;
+
+// Bir performans planında tanımlanabilecek en fazla dilim
+pub const MAX_PERFORMANCE_TRANCHES: u32 = 20;
+
+// Bir planda kaydedilebilecek en fazla hızlandırma
+pub const MAX_ACCELERATIONS: u32 = 20;
+
+// Şirket çapında hızlandırmada tek çağrıda işlenebilecek en fazla plan
+pub const MAX_ACCELERATION_BATCH: u32 = 50;
+
+// Hızlandırma oranının paydası (baz puan)
+pub const BPS_DENOMINATOR: u32 = 10_000;
+
+// Bir hakediş zaman çizelgesi sorgusunda örneklenebilecek en fazla nokta
+pub const MAX_TIMELINE_POINTS: u32 = 50;
+
+// Özel bir hakediş planında tanımlanabilecek en fazla açılış noktası
+pub const MAX_UNLOCK_POINTS: u32 = 50;
+
+// Özel hakediş planında bir açılış noktası
+#[derive(Clone)]
+#[contracttype]
+pub struct UnlockPoint {
+    pub ledger: u32,             // Açılış zamanı (planın saat kaynağına göre)
+    pub cumulative_amount: i128, // Bu noktaya kadar serbest kalan toplam miktar
+}
+
+#[derive(Clone)]
 #[contracttype]
 pub enum VestingType {
     Linear,    // Zaman içinde doğrusal olarak serbest bırakılır
     Cliff,     // Belirli bir süre sonra hepsi bir seferde serbest bırakılır
     Stepped,   // Belirli aralıklarla adım adım serbest bırakılır
+    LinearWithCliff, // Cliff'e kadar hiçbir şey, sonra birikenin tamamı ve doğrusal devam
+    Custom(Vec<UnlockPoint>), // Belirtilen ledger'larda belirtilen toplam miktarlar serbest kalır
+    Performance(PerformanceCondition), // Dilimler zamana göre değil, onaylayıcı onayıyla serbest kalır
+}
+
+// Tamamlanmış bir duraklatma aralığı (planın saat kaynağına göre)
+#[derive(Clone)]
+#[contracttype]
+pub struct PauseInterval {
+    pub from: u64, // Duraklatma zamanı
+    pub to: u64,   // Devam ettirme zamanı
+}
+
+// Performans planında onaylandığında serbest kalan dilim
+#[derive(Clone)]
+#[contracttype]
+pub struct PerformanceTranche {
+    pub amount: i128,           // Dilim miktarı
+    pub deadline: Option<u64>,  // Bu zamandan sonra onaylanmamış dilim fonlayana iade edilebilir
+    pub attested: bool,         // Onaylayıcı tarafından onaylandı mı
+    pub forfeited: bool,        // Süresi dolduğu için iade edildi mi
+}
+
+// Performans planının onaylayıcısı ve dilimleri
+#[derive(Clone)]
+#[contracttype]
+pub struct PerformanceCondition {
+    pub attestor: Address,                  // Dilimleri onaylayan adres
+    pub tranches: Vec<PerformanceTranche>,  // Performans dilimleri
+}
+
+// Zaman çizelgesinde bir örnek: verilen zamana kadar serbest kalan toplam miktar
+#[derive(Clone)]
+#[contracttype]
+pub struct TimelinePoint {
+    pub at: u64,     // Örnek zamanı (planın saat kaynağına göre)
+    pub vested: i128, // Bu zamana kadar serbest kalan toplam miktar
+}
+
+// Bir planın hakediş zaman çizelgesi
+#[derive(Clone)]
+#[contracttype]
+pub struct VestingTimeline {
+    pub schedule_id: u32,            // Hakediş planı kimliği
+    pub clock: VestingClock,         // Örnek zamanlarının saat kaynağı
+    pub samples: Vec<TimelinePoint>, // Örneklenen toplam hakediş miktarları
+    pub next_unlock: Option<u64>,    // Bir sonraki kesikli açılış zamanı (biliniyorsa)
+}
+
+// Eski sürümün planları instance depolamada hak sahibi adresiyle tuttuğu anahtar
+#[contracttype]
+enum LegacyDataKey {
+    VestingSchedule(Address),
+}
+
+// Eski sürümün plan düzeni (kimlik, fonlayan ve sonradan eklenen alanlar yok)
+#[contracttype]
+struct LegacyVestingSchedule {
+    beneficiary: Address,
+    total_amount: i128,
+    start_ledger: u32,
+    duration_ledgers: u32,
+    vesting_type: VestingType,
+    claimed_amount: i128,
+    steps: u32,
+    cliff_ledger: u32,
+}
+
+// Bir hızlandırma kaydı (planın saat kaynağına göre)
+#[derive(Clone)]
+#[contracttype]
+pub struct AccelerationRecord {
+    pub at: u64,                  // Hızlandırma zamanı
+    pub accelerated_amount: i128, // Bu zamandan itibaren takvimden çıkarılmış toplam miktar
+}
+
+// Plan zamanlarının ölçüldüğü saat kaynağı
+#[derive(Clone, Eq, PartialEq)]
+#[contracttype]
+pub enum VestingClock {
+    Ledger,    // Ledger sıra numarası
+    Timestamp, // Ledger kapanış zaman damgası (Unix saniyesi)
 }
 
+#[derive(Clone)]
 #[contracttype]
 pub struct VestingSchedule {
+    pub id: u32,                    // Hakediş planı kimliği
     pub beneficiary: Address,       // Hakediş alan adres
+    pub funder: Address,            // Emaneti fonlayan adres
     pub total_amount: i128,         // Toplam hakediş miktarı
-    pub start_ledger: u32,          // Hakediş başlangıç zamanı (ledger olarak)
-    pub duration_ledgers: u32,      // Toplam hakediş süresi (ledger olarak)
+    pub start_ledger: u32,          // Hakediş başlangıç zamanı (saat kaynağına göre)
+    pub duration_ledgers: u32,      // Toplam hakediş süresi (saat kaynağına göre)
     pub vesting_type: VestingType,  // Hakediş tipi
     pub claimed_amount: i128,       // Şimdiye kadar talep edilen miktar
     pub steps: u32,                 // Adım sayısı (basamaklı hakediş için)
     pub cliff_ledger: u32,          // Cliff zamanı (cliff hakediş için)
+    pub revocable: bool,            // Yönetici tarafından iptal edilebilir mi
+    pub revoked_vested: Option<i128>, // İptal edildiyse o anda sabitlenen hakediş miktarı
+    pub clock: VestingClock,        // Zaman alanlarının saat kaynağı (ledger ya da saniye)
+    pub paused_intervals: Vec<PauseInterval>, // Tamamlanmış duraklatma aralıkları
+    pub paused_at: Option<u64>,     // Şu an duraklatılmışsa duraklatma zamanı
+    pub accelerated_amount: i128,   // Hızlandırma ile takvimden çıkarılıp peşin serbest bırakılan miktar
+    pub accelerations: Vec<AccelerationRecord>, // Hızlandırma geçmişi
+    pub revoked_at: Option<u64>,    // İptal edildiyse iptal zamanı
+}
+
+fn read_schedule(e: &Env, schedule_id: u32) -> Result<VestingSchedule, VestingError> {
+    let key = DataKey::VestingSchedule(schedule_id);
+    let schedule = e
+        .storage()
+        .persistent()
+        .get::<_, VestingSchedule>(&key)
+        .ok_or(VestingError::VestingScheduleDoesNotExist)?;
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+    Ok(schedule)
+}
+
+fn write_schedule(e: &Env, schedule: &VestingSchedule) {
+    let key = DataKey::VestingSchedule(schedule.id);
+    e.storage().persistent().set(&key, schedule);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+// Hak sahibinin hakediş planı kimlikleri
+fn read_schedule_ids(e: &Env, beneficiary: &Address) -> Vec<u32> {
+    let key = DataKey::VestingSchedules(beneficiary.clone());
+    if let Some(ids) = e.storage().persistent().get::<_, Vec<u32>>(&key) {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+        ids
+    } else {
+        Vec::new(e)
+    }
+}
+
+fn write_schedule_ids(e: &Env, beneficiary: &Address, ids: &Vec<u32>) {
+    let key = DataKey::VestingSchedules(beneficiary.clone());
+    e.storage().persistent().set(&key, ids);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+// Sayaçtan yeni bir hakediş planı kimliği ayır
+fn next_schedule_id(e: &Env) -> u32 {
+    let key = DataKey::VestingScheduleCount;
+    let schedule_id = e.storage().instance().get::<_, u32>(&key).unwrap_or(0) + 1;
+    e.storage().instance().set(&key, &schedule_id);
+    schedule_id
+}
+
+// Hak sahibine ait bir planı ya da tüm planlarını oku
+fn read_beneficiary_schedules(
+    e: &Env,
+    beneficiary: &Address,
+    schedule_id: Option<u32>
+) -> Result<Vec<VestingSchedule>, VestingError> {
+    let ids = read_schedule_ids(e, beneficiary);
+    let mut schedules = Vec::new(e);
+    for id in ids.iter() {
+        if schedule_id.is_none() || schedule_id == Some(id) {
+            schedules.push_back(read_schedule(e, id)?);
+        }
+    }
+
+    if schedules.is_empty() {
+        return Err(VestingError::VestingScheduleDoesNotExist);
+    }
+    Ok(schedules)
 }
 
 // Yeni bir hakediş planı oluştur
+#[allow(clippy::too_many_arguments)]
 pub fn create_vesting_schedule(
     e: &Env,
-    admin: Address, 
+    admin: Address,
+    funder: Address,
     beneficiary: Address,
     total_amount: i128,
     start_ledger: u32,
     duration_ledgers: u32,
     vesting_type: VestingType,
     steps: u32,
-    cliff_ledger: u32
-) -> Result<(), VestingError> {
-    // Parametreleri doğrula
-    if total_amount <= 0 || duration_ledgers == 0 {
-        return Err(VestingError::InvalidVestingParameters);
-    }
-    
-    // Hakediş planı zaten var mı kontrol et
-    let key = DataKey::VestingSchedule(beneficiary.clone());
-    if e.storage().instance().has(&key) {
-        return Err(VestingError::VestingScheduleAlreadyExists);
-    }
-    
+    cliff_ledger: u32,
+    revocable: bool,
+    clock: VestingClock
+) -> Result<u32, VestingError> {
+    // Parametreleri doğrula; tokenler kilitlenmeden önce hatalı planlar reddedilir
+    validate_schedule_params(
+        &vesting_type,
+        total_amount,
+        start_ledger,
+        duration_ledgers,
+        steps,
+        cliff_ledger
+    )?;
+
     // Hakediş planını oluştur
+    let schedule_id = next_schedule_id(e);
     let schedule = VestingSchedule {
+        id: schedule_id,
         beneficiary: beneficiary.clone(),
+        funder,
         total_amount,
         start_ledger,
         duration_ledgers,
@@ -63,121 +297,972 @@ pub fn create_vesting_schedule(
         claimed_amount: 0,
         steps,
         cliff_ledger,
+        revocable,
+        revoked_vested: None,
+        clock,
+        paused_intervals: Vec::new(e),
+        paused_at: None,
+        accelerated_amount: 0,
+        accelerations: Vec::new(e),
+        revoked_at: None,
     };
-    
-    // Hakediş planını kaydet
-    e.storage().instance().set(&key, &schedule);
+
+    // Hakediş planını kaydet ve hak sahibinin listesine ekle
+    write_schedule(e, &schedule);
+    let mut ids = read_schedule_ids(e, &beneficiary);
+    ids.push_back(schedule_id);
+    write_schedule_ids(e, &beneficiary, &ids);
     e.storage()
         .instance()
         .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
-    
+
     // Olay yayınla
     e.events().publish(
         ("create_vesting", admin, beneficiary),
-        (total_amount, start_ledger, duration_ledgers)
+        (total_amount, start_ledger, duration_ledgers, schedule_id)
     );
-    
+
+    Ok(schedule_id)
+}
+
+// Hakediş tipine göre plan parametrelerini doğrula
+fn validate_schedule_params(
+    vesting_type: &VestingType,
+    total_amount: i128,
+    start_ledger: u32,
+    duration_ledgers: u32,
+    steps: u32,
+    cliff_ledger: u32
+) -> Result<(), VestingError> {
+    if total_amount <= 0 {
+        return Err(VestingError::InvalidAmount);
+    }
+    if duration_ledgers == 0 {
+        return Err(VestingError::InvalidDuration);
+    }
+
+    // Bitiş ledger'ı u32 sınırını aşmamalı
+    let end_ledger = start_ledger
+        .checked_add(duration_ledgers)
+        .ok_or(VestingError::ScheduleOverflow)?;
+
+    match vesting_type {
+        VestingType::Linear => Ok(()),
+
+        // Cliff hakediş penceresinin içinde olmalı
+        VestingType::Cliff | VestingType::LinearWithCliff => {
+            if cliff_ledger < start_ledger || cliff_ledger > end_ledger {
+                return Err(VestingError::InvalidCliff);
+            }
+            Ok(())
+        },
+
+        // Her adım en az bir ledger sürmeli
+        VestingType::Stepped => {
+            if steps == 0 {
+                return Err(VestingError::InvalidSteps);
+            }
+            if steps > duration_ledgers {
+                return Err(VestingError::StepsExceedDuration);
+            }
+            Ok(())
+        },
+
+        // Açılış noktaları pencere içinde, sıralı ve toplam miktarla tutarlı olmalı
+        VestingType::Custom(points) => {
+            validate_unlock_points(points, total_amount, start_ledger, end_ledger)
+        },
+
+        VestingType::Performance(condition) => validate_tranches(condition, total_amount),
+    }
+}
+
+// Performans dilimlerini doğrula; dilimler toplam miktara eşit olmalı
+fn validate_tranches(condition: &PerformanceCondition, total_amount: i128) -> Result<(), VestingError> {
+    let tranches = &condition.tranches;
+    if tranches.is_empty() || tranches.len() > MAX_PERFORMANCE_TRANCHES {
+        return Err(VestingError::InvalidTranches);
+    }
+
+    let mut sum: i128 = 0;
+    for tranche in tranches.iter() {
+        if tranche.amount <= 0 || tranche.attested || tranche.forfeited {
+            return Err(VestingError::InvalidTranches);
+        }
+        sum = sum.checked_add(tranche.amount).ok_or(VestingError::InvalidTranches)?;
+    }
+
+    if sum != total_amount {
+        return Err(VestingError::InvalidTranches);
+    }
     Ok(())
 }
 
-// Mevcut ledger'da ne kadar token serbest bırakıldığını hesapla
-pub fn calculate_vested_amount(e: &Env, beneficiary: Address) -> Result<i128, VestingError> {
-    let key = DataKey::VestingSchedule(beneficiary.clone());
-    
-    // Hakediş planını al
-    if !e.storage().instance().has(&key) {
-        return Err(VestingError::VestingScheduleDoesNotExist);
+// İade edilmemiş performans dilimlerinin toplamı
+fn unforfeited_amount(condition: &PerformanceCondition) -> i128 {
+    let mut amount: i128 = 0;
+    for tranche in condition.tranches.iter() {
+        if !tranche.forfeited {
+            amount += tranche.amount;
+        }
+    }
+    amount
+}
+
+// Onaylanmış performans dilimlerinin toplamı
+fn attested_amount(condition: &PerformanceCondition) -> i128 {
+    let mut attested: i128 = 0;
+    for tranche in condition.tranches.iter() {
+        if tranche.attested {
+            attested += tranche.amount;
+        }
+    }
+    attested
+}
+
+// Özel plan açılış noktalarını doğrula
+fn validate_unlock_points(
+    points: &Vec<UnlockPoint>,
+    total_amount: i128,
+    start_ledger: u32,
+    end_ledger: u32
+) -> Result<(), VestingError> {
+    if points.is_empty() || points.len() > MAX_UNLOCK_POINTS {
+        return Err(VestingError::InvalidUnlockPoints);
+    }
+
+    let mut previous: Option<UnlockPoint> = None;
+    for point in points.iter() {
+        if point.ledger < start_ledger || point.ledger > end_ledger || point.cumulative_amount < 0 {
+            return Err(VestingError::InvalidUnlockPoints);
+        }
+        // Ledger'lar kesin artan, miktarlar azalmayan olmalı
+        if let Some(prev) = previous {
+            if point.ledger <= prev.ledger || point.cumulative_amount < prev.cumulative_amount {
+                return Err(VestingError::InvalidUnlockPoints);
+            }
+        }
+        previous = Some(point);
+    }
+
+    // Son nokta toplam hakediş miktarına eşit olmalı
+    if points.last().map(|p| p.cumulative_amount) != Some(total_amount) {
+        return Err(VestingError::UnlockPointsMismatch);
+    }
+    Ok(())
+}
+
+// Planın saat kaynağına göre şu anki zaman
+fn current_time(e: &Env, clock: &VestingClock) -> u64 {
+    match clock {
+        VestingClock::Ledger => e.ledger().sequence() as u64,
+        VestingClock::Timestamp => e.ledger().timestamp(),
+    }
+}
+
+// Bir planda mevcut zamanda ne kadar token serbest bırakıldığını hesapla
+fn vested_amount(e: &Env, schedule: &VestingSchedule) -> Result<i128, VestingError> {
+    // İptal edilen planlarda hakediş iptal anında sabitlenir
+    if let Some(vested) = schedule.revoked_vested {
+        return Ok(vested);
+    }
+
+    vested_at(e, schedule, current_time(e, &schedule.clock))
+}
+
+// Başlangıçtan sonra duraklatılmış geçen süre düşülmüş zaman
+fn effective_time(schedule: &VestingSchedule, now: u64) -> u64 {
+    let start = schedule.start_ledger as u64;
+    let overlap = |from: u64, to: u64| to.min(now).saturating_sub(from.max(start));
+
+    let mut paused: u64 = 0;
+    for interval in schedule.paused_intervals.iter() {
+        paused += overlap(interval.from, interval.to);
+    }
+    // Devam eden duraklatma şu ana kadar sürmüş sayılır
+    if let Some(paused_at) = schedule.paused_at {
+        paused += overlap(paused_at, now);
     }
-    
-    let schedule: VestingSchedule = e.storage().instance().get(&key).unwrap();
-    let current_ledger = e.ledger().sequence();
-    
+    now - paused
+}
+
+// Bir planda verilen zamanda serbest bırakılmış olan miktar (hızlandırma dahil)
+fn vested_at(e: &Env, schedule: &VestingSchedule, now: u64) -> Result<i128, VestingError> {
+    // Verilen zamana kadar yapılmış hızlandırmalar sayılır
+    let mut accelerated: i128 = 0;
+    for record in schedule.accelerations.iter() {
+        if record.at > now {
+            break;
+        }
+        accelerated = record.accelerated_amount;
+    }
+
+    // Takvim, hızlandırılan kısım düşüldükten sonra kalan miktar üzerinden işler
+    let scheduled = scheduled_vested_at(e, schedule, schedule.total_amount - accelerated, now)?;
+    Ok(accelerated + scheduled)
+}
+
+// a * b / c, ara çarpım i128 sınırını aşmasın diye 256 bit ile hesaplanır
+pub(crate) fn mul_div(e: &Env, a: i128, b: i128, c: i128) -> Result<i128, VestingError> {
+    if c == 0 {
+        return Err(VestingError::InvalidVestingParameters);
+    }
+    I256::from_i128(e, a)
+        .mul(&I256::from_i128(e, b))
+        .div(&I256::from_i128(e, c))
+        .to_i128()
+        .ok_or(VestingError::ScheduleOverflow)
+}
+
+// Bir planda verilen zamanda takvime göre `amount` içinden serbest bırakılmış olan miktar
+fn scheduled_vested_at(e: &Env, schedule: &VestingSchedule, amount: i128, now: u64) -> Result<i128, VestingError> {
+    // Performans planları takvime değil onaylara bağlıdır
+    if let VestingType::Performance(condition) = &schedule.vesting_type {
+        let attested = attested_amount(condition);
+        let base = unforfeited_amount(condition);
+        if base <= 0 || amount == base {
+            return Ok(attested.min(amount));
+        }
+        return mul_div(e, attested, amount, base);
+    }
+
+    let start = schedule.start_ledger as u64;
+    let duration = schedule.duration_ledgers as u64;
+
+    // Duraklatılan süreler cliff, adım ve bitiş zamanlarını ileri iter
+    let now = effective_time(schedule, now);
+
     // Hakediş başlamadıysa
-    if current_ledger < schedule.start_ledger {
+    if now < start {
         return Ok(0);
     }
-    
+
     // Hakediş tamamlandıysa
-    if current_ledger >= schedule.start_ledger + schedule.duration_ledgers {
-        return Ok(schedule.total_amount);
+    if now >= start + duration {
+        return Ok(amount);
     }
-    
+
     // Hakediş tipine göre serbest bırakılan miktarı hesapla
-    match schedule.vesting_type {
+    let elapsed = now - start;
+    match &schedule.vesting_type {
         VestingType::Linear => {
-            let elapsed = current_ledger - schedule.start_ledger;
-            let vested = (schedule.total_amount * elapsed as i128) / schedule.duration_ledgers as i128;
+            let vested = (amount * elapsed as i128) / duration as i128;
             Ok(vested)
         },
-        
+
+        VestingType::LinearWithCliff => {
+            if now < schedule.cliff_ledger as u64 {
+                return Ok(0);
+            }
+
+            // Cliff'te başlangıçtan bu yana biriken miktar bir seferde serbest kalır
+            let vested = (amount * elapsed as i128) / duration as i128;
+            Ok(vested)
+        },
+
         VestingType::Cliff => {
-            if current_ledger >= schedule.cliff_ledger {
-                Ok(schedule.total_amount)
+            if now >= schedule.cliff_ledger as u64 {
+                Ok(amount)
             } else {
                 Ok(0)
             }
         },
-        
+
         VestingType::Stepped => {
-            if schedule.steps == 0 {
+            // Doğrulamadan önce oluşturulmuş planlarda sıfıra bölmeyi önle
+            if schedule.steps == 0 || schedule.steps > schedule.duration_ledgers {
                 return Err(VestingError::InvalidVestingParameters);
             }
-            
-            let elapsed = current_ledger - schedule.start_ledger;
-            let step_size = schedule.duration_ledgers / schedule.steps;
+
+            let step_size = duration / schedule.steps as u64;
             let completed_steps = elapsed / step_size;
-            
-            if completed_steps >= schedule.steps {
-                Ok(schedule.total_amount)
+
+            if completed_steps >= schedule.steps as u64 {
+                Ok(amount)
             } else {
-                let vested = (schedule.total_amount * completed_steps as i128) / schedule.steps as i128;
+                let vested = (amount * completed_steps as i128) / schedule.steps as i128;
+                Ok(vested)
+            }
+        },
+
+        VestingType::Custom(points) => {
+            // Geçilen son açılış noktasının toplam miktarı
+            let mut vested: i128 = 0;
+            for point in points.iter() {
+                if point.ledger as u64 > now {
+                    break;
+                }
+                vested = point.cumulative_amount;
+            }
+            // Açılış noktaları toplam miktara göre tanımlıdır, hızlandırma sonrası orantılanır
+            if amount == schedule.total_amount {
                 Ok(vested)
+            } else {
+                mul_div(e, vested, amount, schedule.total_amount)
+            }
+        },
+
+        // Performans planları yukarıda ele alındı
+        VestingType::Performance(_) => Ok(0),
+    }
+}
+
+// Duraklatmalar düşülmüş zamanda bir sonraki kesikli açılış (cliff, adım ya da açılış noktası)
+fn next_unlock_at(schedule: &VestingSchedule, effective_now: u64) -> Option<u64> {
+    let start = schedule.start_ledger as u64;
+    let end = start + schedule.duration_ledgers as u64;
+    if effective_now >= end {
+        return None;
+    }
+
+    match &schedule.vesting_type {
+        // Doğrusal hakedişte kesikli açılış yoktur
+        VestingType::Linear => None,
+
+        VestingType::Cliff | VestingType::LinearWithCliff => {
+            let cliff = schedule.cliff_ledger as u64;
+            if effective_now < cliff { Some(cliff) } else { None }
+        },
+
+        VestingType::Stepped => {
+            if schedule.steps == 0 || schedule.steps > schedule.duration_ledgers {
+                return None;
+            }
+            let step_size = schedule.duration_ledgers as u64 / schedule.steps as u64;
+            let completed_steps = effective_now.saturating_sub(start) / step_size;
+            if completed_steps >= schedule.steps as u64 {
+                None
+            } else {
+                Some(start + (completed_steps + 1) * step_size)
             }
+        },
+
+        VestingType::Custom(points) => points
+            .iter()
+            .map(|point| point.ledger as u64)
+            .find(|ledger| *ledger > effective_now),
+
+        // Performans dilimlerinin açılışı zamana bağlı değildir
+        VestingType::Performance(_) => None,
+    }
+}
+
+// Hak sahibinin planları için örneklenmiş hakediş zaman çizelgesi (salt okunur)
+// Zamanlar verilen saat kaynağına göre yorumlanır, diğer saatteki planlar atlanır; hızlandırma
+// ve iptal kayıt zamanlarından itibaren uygulanır, performans dilimleri şu anki durumu yansıtır
+pub fn get_vesting_timeline(
+    e: &Env,
+    beneficiary: Address,
+    from: u64,
+    to: u64,
+    points: u32,
+    clock: VestingClock
+) -> Result<Vec<VestingTimeline>, VestingError> {
+    if from > to || points == 0 || points > MAX_TIMELINE_POINTS {
+        return Err(VestingError::InvalidTimelineRange);
+    }
+
+    // Örnek zamanları aralığa eşit olarak yay (uçlar dahil)
+    let mut sample_times = Vec::new(e);
+    for i in 0..points {
+        let offset = if points == 1 {
+            0
+        } else {
+            ((to - from) as u128 * i as u128 / (points - 1) as u128) as u64
+        };
+        sample_times.push_back(from + offset);
+    }
+
+    let mut timelines = Vec::new(e);
+    for schedule in read_beneficiary_schedules(e, &beneficiary, None)?.iter() {
+        if schedule.clock != clock {
+            continue;
+        }
+        let mut samples = Vec::new(e);
+        for at in sample_times.iter() {
+            let vested = match (schedule.revoked_vested, schedule.revoked_at) {
+                (Some(vested), Some(revoked_at)) if at >= revoked_at => vested,
+                _ => vested_at(e, &schedule, at)?,
+            };
+            samples.push_back(TimelinePoint { at, vested });
+        }
+
+        // Duraklatılmış ya da iptal edilmiş planlarda bir sonraki açılış belirsizdir
+        let next_unlock = if schedule.revoked_vested.is_some() || schedule.paused_at.is_some() {
+            None
+        } else {
+            let now = current_time(e, &schedule.clock);
+            let effective_now = effective_time(&schedule, now);
+            next_unlock_at(&schedule, effective_now).map(|unlock| now + (unlock - effective_now))
+        };
+
+        timelines.push_back(VestingTimeline {
+            schedule_id: schedule.id,
+            clock: clock.clone(),
+            samples,
+            next_unlock,
+        });
+    }
+
+    // Verilen saat kaynağında hiç plan yoksa aralık bu hak sahibi için geçersizdir
+    if timelines.is_empty() {
+        return Err(VestingError::InvalidTimelineRange);
+    }
+    Ok(timelines)
+}
+
+// Hak sahibinin bir planında ya da tüm planlarında serbest bırakılan toplam miktar
+pub fn calculate_vested_amount(
+    e: &Env,
+    beneficiary: Address,
+    schedule_id: Option<u32>
+) -> Result<i128, VestingError> {
+    let mut total: i128 = 0;
+    for schedule in read_beneficiary_schedules(e, &beneficiary, schedule_id)?.iter() {
+        total += vested_amount(e, &schedule)?;
+    }
+    Ok(total)
+}
+
+// Planlarda serbest kalan miktarı talep edilmiş say; ödeme çağırana bırakılır
+fn release_schedules(
+    e: &Env,
+    beneficiary: &Address,
+    schedule_id: Option<u32>
+) -> Result<i128, VestingError> {
+    let mut total_claimed: i128 = 0;
+    for mut schedule in read_beneficiary_schedules(e, beneficiary, schedule_id)?.iter() {
+        // Serbest bırakılan miktarı hesapla
+        let vested = vested_amount(e, &schedule)?;
+        let claimable = vested - schedule.claimed_amount;
+        if claimable <= 0 {
+            continue;
         }
+
+        // Talep edilen miktarı güncelle
+        schedule.claimed_amount += claimable;
+        write_schedule(e, &schedule);
+        total_claimed += claimable;
+
+        // Olay yayınla
+        e.events().publish(
+            ("claim_vested", beneficiary.clone()),
+            (claimable, schedule.claimed_amount, schedule.total_amount, schedule.id)
+        );
     }
+
+    Ok(total_claimed)
 }
 
-// Serbest bırakılan tokenleri talep et
-pub fn claim_vested_tokens(e: &Env, beneficiary: Address) -> Result<i128, VestingError> {
+// Serbest bırakılan tokenleri talep et; plan belirtilmezse tüm planlardan talep edilir
+pub fn claim_vested_tokens(
+    e: &Env,
+    beneficiary: Address,
+    schedule_id: Option<u32>
+) -> Result<i128, VestingError> {
     beneficiary.require_auth();
-    
-    let key = DataKey::VestingSchedule(beneficiary.clone());
-    
-    // Hakediş planını al
-    if !e.storage().instance().has(&key) {
+
+    let total_claimed = release_schedules(e, &beneficiary, schedule_id)?;
+    if total_claimed <= 0 {
+        return Err(VestingError::InsufficientVestedTokens);
+    }
+
+    Ok(total_claimed)
+}
+
+// Birden çok hak sahibinin serbest kalan tokenlerini imzaları olmadan kendilerine gönder
+pub fn release_vested_tokens(e: &Env, beneficiaries: Vec<Address>) -> Result<i128, VestingError> {
+    if beneficiaries.is_empty() || beneficiaries.len() > MAX_RELEASE_BATCH {
+        return Err(VestingError::InvalidBeneficiaries);
+    }
+
+    let mut total_released: i128 = 0;
+    for beneficiary in beneficiaries.iter() {
+        // Planı olmayan ya da serbest kalan miktarı bulunmayan hak sahipleri atlanır
+        if read_schedule_ids(e, &beneficiary).is_empty() {
+            continue;
+        }
+        let released = release_schedules(e, &beneficiary, None)?;
+        if released <= 0 {
+            continue;
+        }
+
+        // Emanetten hak sahibine aktar
+        spend_balance(e, e.current_contract_address(), released);
+        receive_balance(e, beneficiary.clone(), released);
+        TokenUtils::new(e)
+            .events()
+            .transfer(e.current_contract_address(), beneficiary, released);
+        total_released += released;
+    }
+
+    Ok(total_released)
+}
+
+// İptal edilebilir planları durdur; hak edilmemiş kısım fonlayan hesaba iade edilir
+pub fn revoke_vesting_schedules(
+    e: &Env,
+    admin: Address,
+    beneficiary: Address,
+    schedule_id: Option<u32>
+) -> Result<i128, VestingError> {
+    let mut total_refunded: i128 = 0;
+    let mut revoked_any = false;
+
+    for mut schedule in read_beneficiary_schedules(e, &beneficiary, schedule_id)?.iter() {
+        if !schedule.revocable {
+            if schedule_id.is_some() {
+                return Err(VestingError::VestingNotRevocable);
+            }
+            continue;
+        }
+        if schedule.revoked_vested.is_some() {
+            if schedule_id.is_some() {
+                return Err(VestingError::VestingAlreadyRevoked);
+            }
+            continue;
+        }
+
+        // Hakediş miktarını mevcut ledger'da sabitle
+        let vested = vested_amount(e, &schedule)?;
+        let unvested = schedule.total_amount - vested;
+        schedule.revoked_vested = Some(vested);
+        schedule.revoked_at = Some(current_time(e, &schedule.clock));
+        write_schedule(e, &schedule);
+        revoked_any = true;
+
+        // Hak edilmemiş kısmı emanetten fonlayan hesaba iade et
+        if unvested > 0 {
+            spend_balance(e, e.current_contract_address(), unvested);
+            receive_balance(e, schedule.funder.clone(), unvested);
+            TokenUtils::new(e)
+                .events()
+                .transfer(e.current_contract_address(), schedule.funder.clone(), unvested);
+            total_refunded += unvested;
+        }
+
+        // Olay yayınla
+        e.events().publish(
+            ("revoke_vesting", admin.clone(), beneficiary.clone()),
+            (vested, unvested, schedule.id)
+        );
+    }
+
+    if !revoked_any {
+        return Err(VestingError::VestingNotRevocable);
+    }
+
+    Ok(total_refunded)
+}
+
+// Plan devri için yönetici onayı gerekip gerekmediği
+pub fn read_transfer_requires_admin(e: &Env) -> bool {
+    let key = DataKey::VestingTransferRequiresAdmin;
+    e.storage().instance().get::<_, bool>(&key).unwrap_or(false)
+}
+
+pub fn write_transfer_requires_admin(e: &Env, required: bool) {
+    let key = DataKey::VestingTransferRequiresAdmin;
+    e.storage().instance().set(&key, &required);
+}
+
+// Eski hak sahibinin tüm planlarını talep edilen miktarlarıyla birlikte yeni adrese taşı
+pub fn transfer_vesting_schedules(
+    e: &Env,
+    old_beneficiary: Address,
+    new_beneficiary: Address
+) -> Result<u32, VestingError> {
+    let ids = read_schedule_ids(e, &old_beneficiary);
+    if ids.is_empty() {
         return Err(VestingError::VestingScheduleDoesNotExist);
     }
-    
-    let mut schedule: VestingSchedule = e.storage().instance().get(&key).unwrap();
-    
-    // Serbest bırakılan miktarı hesapla
-    let vested_amount = calculate_vested_amount(e, beneficiary.clone())?;
-    let claimable = vested_amount - schedule.claimed_amount;
-    
-    if claimable <= 0 {
-        return Err(VestingError::InsufficientVestedTokens);
+
+    // Planı olan bir hak sahibinin üzerine taşınamaz
+    if !read_schedule_ids(e, &new_beneficiary).is_empty() {
+        return Err(VestingError::BeneficiaryHasSchedule);
     }
-    
-    // Talep edilen miktarı güncelle
-    schedule.claimed_amount += claimable;
-    e.storage().instance().set(&key, &schedule);
-    
+
+    for id in ids.iter() {
+        let mut schedule = read_schedule(e, id)?;
+        schedule.beneficiary = new_beneficiary.clone();
+        write_schedule(e, &schedule);
+    }
+
+    // Plan listesini yeni hak sahibine aktar
+    write_schedule_ids(e, &new_beneficiary, &ids);
+    e.storage()
+        .persistent()
+        .remove(&DataKey::VestingSchedules(old_beneficiary.clone()));
+
     // Olay yayınla
     e.events().publish(
-        ("claim_vested", beneficiary.clone()),
-        (claimable, schedule.claimed_amount, schedule.total_amount)
+        ("transfer_vesting", old_beneficiary, new_beneficiary),
+        ids.clone()
     );
-    
-    Ok(claimable)
+
+    Ok(ids.len())
 }
 
-// Hakediş planı detaylarını al
-pub fn get_vesting_schedule(e: &Env, beneficiary: Address) -> Result<VestingSchedule, VestingError> {
-    let key = DataKey::VestingSchedule(beneficiary.clone());
-    
-    if !e.storage().instance().has(&key) {
-        return Err(VestingError::VestingScheduleDoesNotExist);
+// Planın kalan hak edilmemiş kısmının bir oranını hemen serbest bırak
+fn accelerate_schedule(
+    e: &Env,
+    admin: &Address,
+    schedule: &mut VestingSchedule,
+    percent_bps: u32
+) -> Result<i128, VestingError> {
+    let vested = vested_amount(e, schedule)?;
+    if vested >= schedule.total_amount {
+        return Ok(0);
+    }
+    if schedule.accelerations.len() >= MAX_ACCELERATIONS {
+        return Err(VestingError::AccelerationLimitReached);
+    }
+
+    // Takvimdeki kısmın oranı peşine alınır; bu, kalan hak edilmemiş kısmın aynı oranı demektir
+    let scheduled_part = schedule.total_amount - schedule.accelerated_amount;
+    schedule.accelerated_amount += scheduled_part * percent_bps as i128 / BPS_DENOMINATOR as i128;
+    schedule.accelerations.push_back(AccelerationRecord {
+        at: current_time(e, &schedule.clock),
+        accelerated_amount: schedule.accelerated_amount,
+    });
+    let accelerated = vested_amount(e, schedule)? - vested;
+    if accelerated <= 0 {
+        return Ok(0);
+    }
+    write_schedule(e, schedule);
+
+    // Olay yayınla
+    e.events().publish(
+        ("accelerate_vesting", admin.clone(), schedule.beneficiary.clone()),
+        (accelerated, percent_bps, schedule.id)
+    );
+
+    Ok(accelerated)
+}
+
+// Hak sahibinin iptal edilmemiş planlarını hızlandır (ör. tek ya da çift tetikli hızlandırma)
+pub fn accelerate_vesting_schedules(
+    e: &Env,
+    admin: Address,
+    beneficiary: Address,
+    percent_bps: u32
+) -> Result<i128, VestingError> {
+    if percent_bps == 0 || percent_bps > BPS_DENOMINATOR {
+        return Err(VestingError::InvalidAccelerationBps);
+    }
+
+    let mut total_accelerated: i128 = 0;
+    for mut schedule in read_beneficiary_schedules(e, &beneficiary, None)?.iter() {
+        if schedule.revoked_vested.is_some() {
+            continue;
+        }
+        total_accelerated += accelerate_schedule(e, &admin, &mut schedule, percent_bps)?;
+    }
+
+    Ok(total_accelerated)
+}
+
+// Tüm hak sahiplerinin iptal edilmemiş planlarını hızlandır (şirket çapında olaylar için)
+// Kaynak sınırları nedeniyle planlar kimlik sırasıyla sayfa sayfa işlenir
+pub fn accelerate_all_schedules(
+    e: &Env,
+    admin: Address,
+    percent_bps: u32,
+    start_id: u32,
+    limit: u32
+) -> Result<i128, VestingError> {
+    if percent_bps == 0 || percent_bps > BPS_DENOMINATOR {
+        return Err(VestingError::InvalidAccelerationBps);
+    }
+
+    let count = e
+        .storage()
+        .instance()
+        .get::<_, u32>(&DataKey::VestingScheduleCount)
+        .unwrap_or(0);
+
+    let start_id = start_id.max(1);
+    let end_id = start_id.saturating_add(limit.min(MAX_ACCELERATION_BATCH)).min(count.saturating_add(1));
+
+    let mut total_accelerated: i128 = 0;
+    for schedule_id in start_id..end_id {
+        // Taşınmamış eski planlar atlanır
+        let Ok(mut schedule) = read_schedule(e, schedule_id) else {
+            continue;
+        };
+        // Hızlandırma sınırına ulaşmış planlar sayfanın geri kalanını engellemez
+        if schedule.revoked_vested.is_some() || schedule.accelerations.len() >= MAX_ACCELERATIONS {
+            continue;
+        }
+        total_accelerated += accelerate_schedule(e, &admin, &mut schedule, percent_bps)?;
+    }
+
+    Ok(total_accelerated)
+}
+
+// Hak sahibinin belirtilen ya da iptal edilmemiş tek performans planını bul
+fn read_performance_schedule(
+    e: &Env,
+    beneficiary: &Address,
+    schedule_id: Option<u32>
+) -> Result<(VestingSchedule, PerformanceCondition), VestingError> {
+    let mut found: Option<(VestingSchedule, PerformanceCondition)> = None;
+    for schedule in read_beneficiary_schedules(e, beneficiary, schedule_id)?.iter() {
+        if schedule.revoked_vested.is_some() {
+            if schedule_id.is_some() {
+                return Err(VestingError::VestingAlreadyRevoked);
+            }
+            continue;
+        }
+        if let VestingType::Performance(condition) = schedule.vesting_type.clone() {
+            // Plan belirtilmediyse ve birden fazla performans planı varsa dilim belirsizdir
+            if found.is_some() {
+                return Err(VestingError::AmbiguousSchedule);
+            }
+            found = Some((schedule, condition));
+        }
+    }
+    found.ok_or(VestingError::VestingScheduleDoesNotExist)
+}
+
+// Performans dilimini onayla; onaylanan dilim talep edilebilir hale gelir
+pub fn attest_tranche(
+    e: &Env,
+    beneficiary: Address,
+    tranche_index: u32,
+    schedule_id: Option<u32>
+) -> Result<i128, VestingError> {
+    let (mut schedule, mut condition) = read_performance_schedule(e, &beneficiary, schedule_id)?;
+    condition.attestor.require_auth();
+
+    let mut tranche = condition
+        .tranches
+        .get(tranche_index)
+        .ok_or(VestingError::TrancheNotFound)?;
+    if tranche.attested {
+        return Err(VestingError::TrancheAlreadyAttested);
+    }
+    if tranche.forfeited {
+        return Err(VestingError::TrancheForfeited);
+    }
+
+    // Son tarihten sonra onay verilemez
+    let now = current_time(e, &schedule.clock);
+    if tranche.deadline.is_some_and(|deadline| now > deadline) {
+        return Err(VestingError::TrancheExpired);
+    }
+
+    tranche.attested = true;
+    condition.tranches.set(tranche_index, tranche.clone());
+    schedule.vesting_type = VestingType::Performance(condition.clone());
+    write_schedule(e, &schedule);
+
+    // Olay yayınla
+    e.events().publish(
+        ("attest_milestone", condition.attestor, beneficiary),
+        (tranche.amount, tranche_index, schedule.id)
+    );
+
+    Ok(tranche.amount)
+}
+
+// Son tarihi geçmiş onaylanmamış dilimi fonlayana iade et (herkes çağırabilir)
+pub fn forfeit_performance_tranche(
+    e: &Env,
+    beneficiary: Address,
+    tranche_index: u32,
+    schedule_id: Option<u32>
+) -> Result<i128, VestingError> {
+    let (mut schedule, mut condition) = read_performance_schedule(e, &beneficiary, schedule_id)?;
+
+    let mut tranche = condition
+        .tranches
+        .get(tranche_index)
+        .ok_or(VestingError::TrancheNotFound)?;
+    if tranche.attested {
+        return Err(VestingError::TrancheAlreadyAttested);
+    }
+    if tranche.forfeited {
+        return Err(VestingError::TrancheForfeited);
+    }
+
+    let now = current_time(e, &schedule.clock);
+    if tranche.deadline.is_none_or(|deadline| now <= deadline) {
+        return Err(VestingError::DeadlineNotReached);
     }
-    
-    Ok(e.storage().instance().get(&key).unwrap())
-}
\ No newline at end of file
+
+    // Dilimin sadece takvimde kalan payı iade edilir; hızlandırılan kısım hak sahibinindir
+    let base = unforfeited_amount(&condition);
+    let refund = mul_div(e, schedule.total_amount - schedule.accelerated_amount, tranche.amount, base)?;
+
+    tranche.forfeited = true;
+    condition.tranches.set(tranche_index, tranche);
+    schedule.vesting_type = VestingType::Performance(condition);
+    schedule.total_amount -= refund;
+    write_schedule(e, &schedule);
+
+    // İade edilen kısmı emanetten fonlayan hesaba aktar
+    if refund > 0 {
+        spend_balance(e, e.current_contract_address(), refund);
+        receive_balance(e, schedule.funder.clone(), refund);
+        TokenUtils::new(e)
+            .events()
+            .transfer(e.current_contract_address(), schedule.funder.clone(), refund);
+    }
+
+    // Olay yayınla
+    e.events().publish(
+        ("forfeit_tranche", beneficiary),
+        (refund, tranche_index, schedule.id)
+    );
+
+    Ok(refund)
+}
+
+// Planların hakediş birikimini durdur; plan belirtilmezse tüm etkin planlar duraklatılır
+pub fn pause_vesting_schedules(
+    e: &Env,
+    admin: Address,
+    beneficiary: Address,
+    schedule_id: Option<u32>
+) -> Result<u32, VestingError> {
+    let mut paused_count: u32 = 0;
+
+    for mut schedule in read_beneficiary_schedules(e, &beneficiary, schedule_id)?.iter() {
+        if schedule.revoked_vested.is_some() || schedule.paused_at.is_some() {
+            if schedule_id.is_some() {
+                return Err(if schedule.paused_at.is_some() {
+                    VestingError::VestingAlreadyPaused
+                } else {
+                    VestingError::VestingAlreadyRevoked
+                });
+            }
+            continue;
+        }
+        if schedule.paused_intervals.len() >= MAX_PAUSE_INTERVALS {
+            return Err(VestingError::PauseLimitReached);
+        }
+
+        let now = current_time(e, &schedule.clock);
+        schedule.paused_at = Some(now);
+        write_schedule(e, &schedule);
+        paused_count += 1;
+
+        // Olay yayınla
+        e.events().publish(
+            ("pause_vesting", admin.clone(), beneficiary.clone()),
+            (now, schedule.id)
+        );
+    }
+
+    if paused_count == 0 {
+        return Err(VestingError::VestingAlreadyPaused);
+    }
+
+    Ok(paused_count)
+}
+
+// Duraklatılmış planlara devam et; duraklatma süresi bitiş zamanına eklenir
+pub fn resume_vesting_schedules(
+    e: &Env,
+    admin: Address,
+    beneficiary: Address,
+    schedule_id: Option<u32>
+) -> Result<u32, VestingError> {
+    let mut resumed_count: u32 = 0;
+
+    for mut schedule in read_beneficiary_schedules(e, &beneficiary, schedule_id)?.iter() {
+        let Some(paused_at) = schedule.paused_at else {
+            if schedule_id.is_some() {
+                return Err(VestingError::VestingNotPaused);
+            }
+            continue;
+        };
+
+        // Duraklatma aralığını kaydet
+        let now = current_time(e, &schedule.clock);
+        schedule.paused_intervals.push_back(PauseInterval { from: paused_at, to: now });
+        schedule.paused_at = None;
+        write_schedule(e, &schedule);
+        resumed_count += 1;
+
+        // Olay yayınla
+        e.events().publish(
+            ("resume_vesting", admin.clone(), beneficiary.clone()),
+            (now - paused_at, schedule.id)
+        );
+    }
+
+    if resumed_count == 0 {
+        return Err(VestingError::VestingNotPaused);
+    }
+
+    Ok(resumed_count)
+}
+
+// Eski sürümün hak sahibi adresine göre verilmiş planları kalıcı depolamaya taşı
+// Eski anahtarlar listelenemediğinden hak sahipleri çağıran tarafından verilir
+pub fn migrate_vesting_storage(
+    e: &Env,
+    admin: Address,
+    beneficiaries: Vec<Address>
+) -> Result<u32, VestingError> {
+    if beneficiaries.is_empty() || beneficiaries.len() > MAX_RELEASE_BATCH {
+        return Err(VestingError::InvalidBeneficiaries);
+    }
+
+    let mut migrated: u32 = 0;
+    for beneficiary in beneficiaries.iter() {
+        let key = LegacyDataKey::VestingSchedule(beneficiary.clone());
+        let Some(legacy) = e.storage().instance().get::<_, LegacyVestingSchedule>(&key) else {
+            continue;
+        };
+
+        // Eski plan yönetici tarafından fonlanmış, iptal edilemez bir ledger planıdır
+        let schedule_id = next_schedule_id(e);
+        let schedule = VestingSchedule {
+            id: schedule_id,
+            beneficiary: beneficiary.clone(),
+            funder: admin.clone(),
+            total_amount: legacy.total_amount,
+            start_ledger: legacy.start_ledger,
+            duration_ledgers: legacy.duration_ledgers,
+            vesting_type: legacy.vesting_type,
+            claimed_amount: legacy.claimed_amount,
+            steps: legacy.steps,
+            cliff_ledger: legacy.cliff_ledger,
+            revocable: false,
+            revoked_vested: None,
+            clock: VestingClock::Ledger,
+            paused_intervals: Vec::new(e),
+            paused_at: None,
+            accelerated_amount: 0,
+            accelerations: Vec::new(e),
+            revoked_at: None,
+        };
+        write_schedule(e, &schedule);
+        let mut ids = read_schedule_ids(e, &beneficiary);
+        ids.push_back(schedule_id);
+        write_schedule_ids(e, &beneficiary, &ids);
+        e.storage().instance().remove(&key);
+        migrated += 1;
+
+        // Olay yayınla
+        e.events().publish(
+            ("migrate_vesting", admin.clone(), beneficiary),
+            (schedule.total_amount, schedule.claimed_amount, schedule_id)
+        );
+    }
+
+    // Verilen hak sahiplerinin hiçbirinde taşınacak plan yoksa
+    if migrated == 0 {
+        return Err(VestingError::MigrationAlreadyDone);
+    }
+
+    Ok(migrated)
+}
+
+// Hak sahibinin tüm hakediş planlarını al
+pub fn get_vesting_schedules(e: &Env, beneficiary: Address) -> Result<Vec<VestingSchedule>, VestingError> {
+    read_beneficiary_schedules(e, &beneficiary, None)
+}